@@ -241,7 +241,7 @@ mod louds {
                         // iter_batched() does not properly time `routine` time when `setup` time is far longer than `routine` time.
                         // Tested function takes too short compared to build(). So loop many times.
                         for _ in 0..times {
-                            let _ = louds.node_num_to_index(&LoudsNodeNum::new(n - 1));
+                            let _ = louds.node_num_to_index(LoudsNodeNum::new(n - 1));
                         }
                     },
                     BatchSize::SmallInput,
@@ -270,7 +270,7 @@ mod louds {
                         // iter_batched() does not properly time `routine` time when `setup` time is far longer than `routine` time.
                         // Tested function takes too short compared to build(). So loop many times.
                         for _ in 0..times {
-                            let _ = louds.index_to_node_num(&LoudsIndex::new(n / 2 + 1));
+                            let _ = louds.index_to_node_num(LoudsIndex::new(n / 2 + 1));
                         }
                     },
                     BatchSize::SmallInput,
@@ -299,7 +299,7 @@ mod louds {
                         // iter_batched() does not properly time `routine` time when `setup` time is far longer than `routine` time.
                         // Tested function takes too short compared to build(). So loop many times.
                         for _ in 0..times {
-                            let _ = louds.parent_to_children(&LoudsNodeNum::new(n - 1));
+                            let _ = louds.parent_to_children(LoudsNodeNum::new(n - 1));
                         }
                     },
                     BatchSize::SmallInput,
@@ -328,7 +328,7 @@ mod louds {
                         // iter_batched() does not properly time `routine` time when `setup` time is far longer than `routine` time.
                         // Tested function takes too short compared to build(). So loop many times.
                         for _ in 0..times {
-                            let _ = louds.child_to_parent(&LoudsIndex::new(n / 2 + 1));
+                            let _ = louds.child_to_parent(LoudsIndex::new(n / 2 + 1));
                         }
                     },
                     BatchSize::SmallInput,
@@ -3,6 +3,7 @@ set -eux
 
 cargo build --release --verbose --all
 cargo test --release --verbose --all
+cargo test --release --verbose --all --all-features
 cargo fmt --all -- --check
 cargo doc
 cargo bench --all
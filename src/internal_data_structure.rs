@@ -1,2 +1,6 @@
+pub mod binary_io;
 pub mod popcount_table;
 pub mod raw_bit_vector;
+pub mod select_index;
+pub mod serialization_header;
+pub mod zero_pair_index;
@@ -0,0 +1,72 @@
+//! Little-endian primitives shared by serialization formats.
+
+use std::io::{self, Read, Write};
+
+/// Upper bound of the capacity reserved by [read_u64_words()](fn.read_u64_words.html) before reading.
+const PREALLOCATED_WORDS_MAX: u64 = 1 << 16;
+
+pub fn read_u8<R: Read>(r: &mut R) -> io::Result<u8> {
+    let mut buf = [0u8; 1];
+    r.read_exact(&mut buf)?;
+    Ok(buf[0])
+}
+
+pub fn read_u16<R: Read>(r: &mut R) -> io::Result<u16> {
+    let mut buf = [0u8; 2];
+    r.read_exact(&mut buf)?;
+    Ok(u16::from_le_bytes(buf))
+}
+
+pub fn read_u32<R: Read>(r: &mut R) -> io::Result<u32> {
+    let mut buf = [0u8; 4];
+    r.read_exact(&mut buf)?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+pub fn read_u64<R: Read>(r: &mut R) -> io::Result<u64> {
+    let mut buf = [0u8; 8];
+    r.read_exact(&mut buf)?;
+    Ok(u64::from_le_bytes(buf))
+}
+
+/// Reads `count` words one by one.
+///
+/// `count` usually comes from a header and cannot be trusted, so the buffer grows as words actually arrive instead of being allocated up front.
+/// When the input ends early, returns an `io::ErrorKind::InvalidData` error.
+pub fn read_u64_words<R: Read>(r: &mut R, count: u64) -> io::Result<Vec<u64>> {
+    let mut words = Vec::with_capacity(count.min(PREALLOCATED_WORDS_MAX) as usize);
+    for i in 0..count {
+        match read_u64(r) {
+            Ok(word) => words.push(word),
+            Err(ref e) if e.kind() == io::ErrorKind::UnexpectedEof => {
+                return Err(invalid_data(format!(
+                    "input ends after {} of {} words",
+                    i, count
+                )));
+            }
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(words)
+}
+
+pub fn write_u8<W: Write>(w: &mut W, v: u8) -> io::Result<()> {
+    w.write_all(&[v])
+}
+
+pub fn write_u16<W: Write>(w: &mut W, v: u16) -> io::Result<()> {
+    w.write_all(&v.to_le_bytes())
+}
+
+pub fn write_u32<W: Write>(w: &mut W, v: u32) -> io::Result<()> {
+    w.write_all(&v.to_le_bytes())
+}
+
+pub fn write_u64<W: Write>(w: &mut W, v: u64) -> io::Result<()> {
+    w.write_all(&v.to_le_bytes())
+}
+
+/// Makes an `io::ErrorKind::InvalidData` error.
+pub fn invalid_data(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
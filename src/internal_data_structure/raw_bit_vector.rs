@@ -1,14 +1,17 @@
 use crate::BitString;
 use std::fmt;
 
-#[derive(PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Debug)]
 /// Bit vector of arbitrary length (actually the length is limited to _[1, 2^64)_).
-pub struct RawBitVector {
-    byte_vec: Vec<u8>,
-    last_byte_len: u8,
+///
+/// Bits are packed into `u64` words held by any storage `S` that can be viewed as `&[u64]` (`Vec<u64>`, `Box<[u64]>`, `Arc<[u64]>`, `&[u64]`, ...).
+/// _i_-th bit is the _(i % 64)_-th least significant bit of _(i / 64)_-th word (the same layout as sdsl-lite's `bit_vector`).
+pub struct RawBitVector<S = Vec<u64>> {
+    words: S,
+    length: u64,
 }
 
-impl RawBitVector {
+impl RawBitVector<Vec<u64>> {
     /// Makes a bit vector of `length`, willed with 0.
     ///
     /// # Panics
@@ -16,16 +19,9 @@ impl RawBitVector {
     pub fn from_length(length: u64) -> Self {
         assert!(length > 0, "length must be > 0.");
 
-        let last_byte_len_or_0 = (length % 8) as u8;
-        let last_byte_len = if last_byte_len_or_0 == 0 {
-            8
-        } else {
-            last_byte_len_or_0
-        };
-
         Self {
-            byte_vec: vec![0; ((length - 1) / 8 + 1) as usize],
-            last_byte_len,
+            words: vec![0; RawBitVector::calc_words_cnt(length)],
+            length,
         }
     }
 
@@ -40,56 +36,66 @@ impl RawBitVector {
         rbv
     }
 
-    /// Returns i-th bit.
+    /// Appends `bit` to the end, growing the words when needed.
+    pub fn push(&mut self, bit: bool) {
+        if self.words.len() < RawBitVector::calc_words_cnt(self.length + 1) {
+            self.words.push(0);
+        }
+        self.length += 1;
+        if bit {
+            self.set_bit(self.length - 1);
+        }
+    }
+
+    /// Returns the number of `u64` words to hold `length` bits.
+    pub fn calc_words_cnt(length: u64) -> usize {
+        length.div_ceil(64) as usize
+    }
+}
+
+impl<S: AsRef<[u64]>> RawBitVector<S> {
+    /// Makes a bit vector of `length` on top of already packed `words`.
+    ///
+    /// Bits in `words` beyond `length` are ignored.
     ///
     /// # Panics
-    /// When _`i` >= `self.length()`_.
-    pub fn access(&self, i: u64) -> bool {
-        self.validate_index(i);
-        let byte = self.byte_vec[(i / 8) as usize];
-        match i % 8 {
-            0 => byte & 0b1000_0000 != 0,
-            1 => byte & 0b0100_0000 != 0,
-            2 => byte & 0b0010_0000 != 0,
-            3 => byte & 0b0001_0000 != 0,
-            4 => byte & 0b0000_1000 != 0,
-            5 => byte & 0b0000_0100 != 0,
-            6 => byte & 0b0000_0010 != 0,
-            7 => byte & 0b0000_0001 != 0,
-            _ => panic!("never happen"),
-        }
+    /// When:
+    /// - _`length` == 0_.
+    /// - `words` has less than _ceil(`length` / 64)_ words.
+    pub fn from_words(words: S, length: u64) -> Self {
+        assert!(length > 0, "length must be > 0.");
+        assert!(
+            words.as_ref().len() >= RawBitVector::calc_words_cnt(length),
+            "{} words are too short to hold {} bits",
+            words.as_ref().len(),
+            length
+        );
+
+        Self { words, length }
     }
 
-    /// Set 1 to i-th bit.
+    /// Returns i-th bit.
     ///
     /// # Panics
     /// When _`i` >= `self.length()`_.
-    pub fn set_bit(&mut self, i: u64) {
+    pub fn access(&self, i: u64) -> bool {
         self.validate_index(i);
-        let byte = self.byte_vec[(i / 8) as usize];
-        self.byte_vec[(i / 8) as usize] = match i % 8 {
-            0 => byte | 0b1000_0000,
-            1 => byte | 0b0100_0000,
-            2 => byte | 0b0010_0000,
-            3 => byte | 0b0001_0000,
-            4 => byte | 0b0000_1000,
-            5 => byte | 0b0000_0100,
-            6 => byte | 0b0000_0010,
-            7 => byte | 0b0000_0001,
-            _ => panic!("never happen"),
-        }
+        let word = self.words.as_ref()[(i / 64) as usize];
+        word & (1 << (i % 64)) != 0
     }
 
     /// Returns length.
     pub fn length(&self) -> u64 {
-        (self.byte_vec.len() as u64 - 1) * 8 + (self.last_byte_len as u64)
+        self.length
     }
 
     /// Returns popcount of whole this SuccinctBitVector.
     pub fn popcount(&self) -> u64 {
-        self.byte_vec
-            .iter()
-            .fold(0, |popcnt: u64, byte| byte.count_ones() as u64 + popcnt)
+        let words = &self.words.as_ref()[..RawBitVector::calc_words_cnt(self.length)];
+        let (last, init) = words.split_last().expect("length must be > 0");
+        init.iter()
+            .fold(0, |popcnt: u64, word| word.count_ones() as u64 + popcnt)
+            + (last & RawBitVector::last_word_mask(self.length)).count_ones() as u64
     }
 
     /// Makes another RawBitVector from _[`i`, `i` + `size`)_ of self.
@@ -98,7 +104,7 @@ impl RawBitVector {
     /// When:
     /// - _`i` + `size` >= `self.length()`_
     /// - _`size` == 0_
-    pub fn copy_sub(&self, i: u64, size: u64) -> Self {
+    pub fn copy_sub(&self, i: u64, size: u64) -> RawBitVector {
         self.validate_index(i);
         assert!(
             i + size <= self.length(),
@@ -109,79 +115,62 @@ impl RawBitVector {
         );
         assert!(size > 0, "length must be > 0");
 
-        let mut sub_byte_vec: Vec<u8> = Vec::with_capacity(size as usize / 8 + 1);
-
         // Memo for implementation:
-        // Assume self.byte_vec == 00000000 11111111 00000000 11111
-        //
-        // 00000000 11111111 00000000 11111
-        //        ^                     ^
-        //        i=7                   i+size = 7+19 = 26
-        //      (start)                 (end)
-        //
-        // Copy [start, end).
-        //
-        // Use j for iterator:
-        //   j = start, start+8, ..., start+16 (>= end - 8)
+        // Copy [start, end) word by word. Each copied word consists of the higher bits of
+        // `words[j / 64]` and the lower bits of `words[j / 64 + 1]` (which can be a sentinel).
+        let words = self.words.as_ref();
         let start = i;
         let end = start + size;
-
-        if start % 8 == 0 {
-            for j in (start..end).step_by(8) {
-                // Copy 1~8 bits from a single byte in self.byte_vec.
-                let byte = self.byte_vec[j as usize / 8];
-                let right_bits_to_discard = if end - j >= 8 { 0 } else { 8 - (end - j) };
-                let copied_byte = (byte >> right_bits_to_discard) << right_bits_to_discard;
-                sub_byte_vec.push(copied_byte);
-            }
-        } else {
-            let left_bits_to_discard_from_byte1 = start % 8;
-            let right_bits_to_discard_from_byte2 = 8 - left_bits_to_discard_from_byte1;
-
-            for j in (start..end).step_by(8) {
-                // Copy 1~8 bits from 2 byte in self.byte_vec (second byte can be a sentinel).
-                let i_byte1 = j as usize / 8;
-                let byte1 = self.byte_vec[i_byte1];
-                let byte2 = if i_byte1 + 1 < self.byte_vec.len() {
-                    self.byte_vec[i_byte1 + 1]
-                } else {
-                    0u8
-                };
-
-                let copied_byte = (byte1 << left_bits_to_discard_from_byte1)
-                    | (byte2 >> right_bits_to_discard_from_byte2);
-                let right_bits_to_discard_from_copied_byte =
-                    if end - j >= 8 { 0 } else { 8 - (end - j) };
-                let copied_byte = copied_byte >> right_bits_to_discard_from_copied_byte
-                    << right_bits_to_discard_from_copied_byte;
-                sub_byte_vec.push(copied_byte);
-            }
+        let shift = start % 64;
+
+        let mut sub_words: Vec<u64> = Vec::with_capacity(RawBitVector::calc_words_cnt(size));
+        for j in (start..end).step_by(64) {
+            let i_word1 = (j / 64) as usize;
+            let word1 = words[i_word1] >> shift;
+            let word2 = if shift == 0 || i_word1 + 1 >= words.len() {
+                0u64
+            } else {
+                words[i_word1 + 1] << (64 - shift)
+            };
+            sub_words.push(word1 | word2);
+        }
+        if let Some(last) = sub_words.last_mut() {
+            *last &= RawBitVector::last_word_mask(size);
         }
 
-        Self {
-            byte_vec: sub_byte_vec,
-            last_byte_len: if size % 8 == 0 { 8 } else { (size % 8) as u8 },
+        RawBitVector {
+            words: sub_words,
+            length: size,
         }
     }
 
-    /// Returns a concatenated number of `self.byte_vec[0, 3]`.
+    /// Returns a concatenated number of the first 32 bits; the first bit goes to the most significant bit.
     ///
     /// # Panics
-    /// If _`self.byte_vec.len()` > 4_
+    /// If _`self.length()` > 32_
     pub fn as_u32(&self) -> u32 {
         assert!(
-            self.byte_vec.len() <= 4,
-            "self.byte_vec.len() = {} must be <= 4",
-            self.byte_vec.len()
+            self.length <= 32,
+            "self.length() = {} must be <= 32",
+            self.length
         );
-        let bv = &self.byte_vec;
+        let word = self.words.as_ref()[0] & RawBitVector::last_word_mask(self.length);
+        (word as u32).reverse_bits()
+    }
 
-        let byte0 = if bv.len() > 0 { bv[0] as u32 } else { 0u32 };
-        let byte1 = if bv.len() > 1 { bv[1] as u32 } else { 0u32 };
-        let byte2 = if bv.len() > 2 { bv[2] as u32 } else { 0u32 };
-        let byte3 = if bv.len() > 3 { bv[3] as u32 } else { 0u32 };
+    /// Returns the underlying words.
+    pub fn words(&self) -> &S {
+        &self.words
+    }
 
-        (byte0 << 24) | (byte1 << 16) | (byte2 << 8) | byte3
+    /// Consumes self and returns the underlying words.
+    pub fn into_words(self) -> S {
+        self.words
+    }
+
+    /// Replaces the underlying words with `f(words)`, keeping the bits.
+    pub fn map_words<T: AsRef<[u64]>, F: FnOnce(S) -> T>(self, f: F) -> RawBitVector<T> {
+        RawBitVector::from_words(f(self.words), self.length)
     }
 
     /// # Panics
@@ -195,25 +184,32 @@ impl RawBitVector {
     }
 }
 
-impl fmt::Display for RawBitVector {
+impl<S: AsRef<[u64]> + AsMut<[u64]>> RawBitVector<S> {
+    /// Set 1 to i-th bit.
+    ///
+    /// # Panics
+    /// When _`i` >= `self.length()`_.
+    pub fn set_bit(&mut self, i: u64) {
+        self.validate_index(i);
+        self.words.as_mut()[(i / 64) as usize] |= 1 << (i % 64);
+    }
+}
+
+impl RawBitVector {
+    /// Mask to pick valid bits from the last word of a bit vector of `length`.
+    pub fn last_word_mask(length: u64) -> u64 {
+        match length % 64 {
+            0 => !0u64,
+            r => (1u64 << r) - 1,
+        }
+    }
+}
+
+impl<S: AsRef<[u64]>> fmt::Display for RawBitVector<S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let bits_str = self
-            .byte_vec
-            .iter()
-            .enumerate()
-            .map(|(i, byte)| {
-                let byte_s = format!("{: >8}", format!("{:b}", byte)).replace(' ', "0");
-                if i < self.byte_vec.len() - 1 {
-                    byte_s
-                } else {
-                    byte_s
-                        .chars()
-                        .take(self.last_byte_len as usize)
-                        .collect::<String>()
-                }
-            })
-            .collect::<Vec<String>>()
-            .concat();
+        let bits_str = (0..self.length())
+            .map(|i| if self.access(i) { '1' } else { '0' })
+            .collect::<String>();
 
         write!(f, "{}", bits_str)
     }
@@ -402,6 +398,58 @@ mod from_bit_string_failure_tests {
     // well-tested in BitString
 }
 
+#[cfg(test)]
+mod from_words_success_tests {
+    use super::RawBitVector;
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_words, in_length, expected_str): (Vec<u64>, u64, &str) = $value;
+                let rbv = RawBitVector::from_words(&in_words[..], in_length);
+                assert_eq!(format!("{}", rbv), expected_str);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: (vec!(0b0), 1, "0"),
+        t1_2: (vec!(0b1), 1, "1"),
+        t1_3: (vec!(0b10), 1, "0"),
+
+        t5_1: (vec!(0b10010), 5, "01001"),
+
+        t65_1: (vec!(1, 1), 65, "10000000000000000000000000000000000000000000000000000000000000001"),
+        t65_2: (vec!(1, 1, 1), 65, "10000000000000000000000000000000000000000000000000000000000000001"),
+    }
+
+    #[test]
+    fn popcount_ignores_bits_beyond_length() {
+        let rbv = RawBitVector::from_words(vec![!0u64, !0u64], 65);
+        assert_eq!(rbv.popcount(), 65);
+    }
+}
+
+#[cfg(test)]
+mod from_words_failure_tests {
+    use super::RawBitVector;
+
+    #[test]
+    #[should_panic]
+    fn empty() {
+        let _ = RawBitVector::from_words(vec![0u64], 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn words_too_short() {
+        let _ = RawBitVector::from_words(vec![0u64], 65);
+    }
+}
+
 #[cfg(test)]
 mod length_success_tests {
     use super::RawBitVector;
@@ -579,6 +627,46 @@ mod set_bit_failure_tests {
     }
 }
 
+#[cfg(test)]
+mod push_success_tests {
+    use super::RawBitVector;
+    use crate::BitString;
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, pushed_s) = $value;
+                let mut rbv = RawBitVector::from_bit_string(&BitString::new(in_s));
+                for c in pushed_s.chars() {
+                    rbv.push(c == '1');
+                }
+                let expected = RawBitVector::from_bit_string(&BitString::new(&format!("{}{}", in_s, pushed_s)));
+                assert_eq!(rbv.length(), expected.length());
+                assert_eq!(format!("{}", rbv), format!("{}", expected));
+                assert_eq!(rbv.popcount(), expected.popcount());
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: ("0", "1"),
+        t2: ("1", "0"),
+        t3: ("10", "110100"),
+        t4: ("1", "01010101010101010101010101010101010101010101010101010101010101010"),
+        t5: (
+            "1111111111111111111111111111111111111111111111111111111111111111",
+            "1"
+        ),
+        t6: (
+            "1111111111111111111111111111111111111111111111111111111111111111",
+            "00000000000000000000000000000000000000000000000000000000000000001"
+        ),
+    }
+}
+
 #[cfg(test)]
 mod copy_sub_success_tests {
     use super::{BitString, RawBitVector};
@@ -0,0 +1,237 @@
+use super::raw_bit_vector::RawBitVector;
+
+/// The number of target bits per sample.
+const SAMPLE_INTERVAL: u64 = 128;
+
+/// A block spanning this many bits or more stores the positions of all its target bits.
+const LONG_BLOCK_SPAN: u64 = 8192;
+
+/// Set in `samples` for a long block; the rest of the bits is the index into `long_blocks`.
+const LONG_BLOCK_FLAG: u64 = 1 << 63;
+
+#[derive(Clone)]
+/// Constant-time select of '1' (or '0') over raw words, by sampling every `SAMPLE_INTERVAL`-th target bit.
+///
+/// Target bits are grouped into blocks of `SAMPLE_INTERVAL`.
+/// A block shorter than `LONG_BLOCK_SPAN` bits keeps the position of its first target, and the rest is found by scanning at most `LONG_BLOCK_SPAN / 64` words.
+/// A longer block keeps the offsets of all its targets from the first one, packed in just enough bits for the block's span.
+///
+/// Samples take 64 bits per `SAMPLE_INTERVAL` targets.
+/// A long block spanning _S_ bits takes _`SAMPLE_INTERVAL` * (floor(log2 S) + 1) + 192_ bits, which is less than 1/4 bit per bit it spans.
+pub struct SelectIndex {
+    /// Selects '1' if true, '0' if false.
+    bit: bool,
+
+    /// The number of target bits.
+    count: u64,
+
+    /// Position of each block's first target, or `LONG_BLOCK_FLAG | i` for `i`-th long block.
+    samples: Vec<u64>,
+
+    long_blocks: Vec<LongBlock>,
+
+    /// Offsets of targets in long blocks, packed as bit fields of each block's `width`.
+    long_offsets: Vec<u64>,
+
+    /// The number of bits used in `long_offsets`.
+    long_offsets_len: u64,
+}
+
+#[derive(Clone)]
+/// A block spanning `LONG_BLOCK_SPAN` bits or more.
+struct LongBlock {
+    /// Position of the first target.
+    first: u64,
+
+    /// Bit width of each offset from `first`.
+    width: u64,
+
+    /// Where the offsets start in `long_offsets`, in bits.
+    start: u64,
+}
+
+impl SelectIndex {
+    /// Builds the samples of `bit` over the first `length` bits of `words` in _O(N)_ time.
+    ///
+    /// The `i`-th bit is the _(i % 64)_-th least significant bit of _(i / 64)_-th word.
+    pub fn new(words: &[u64], length: u64, bit: bool) -> Self {
+        let mut index = SelectIndex {
+            bit,
+            count: 0,
+            samples: vec![],
+            long_blocks: vec![],
+            long_offsets: vec![],
+            long_offsets_len: 0,
+        };
+
+        let mut block: Vec<u64> = Vec::with_capacity(SAMPLE_INTERVAL as usize);
+        for i_word in 0..RawBitVector::calc_words_cnt(length) {
+            let mut word = index.target_word(words, length, i_word);
+            while word != 0 {
+                block.push(i_word as u64 * 64 + word.trailing_zeros() as u64);
+                word &= word - 1;
+                if block.len() as u64 == SAMPLE_INTERVAL {
+                    index.push_block(&block);
+                    block.clear();
+                }
+            }
+        }
+        if !block.is_empty() {
+            index.push_block(&block);
+        }
+        index
+    }
+
+    /// Returns the position (0-origin) of the `num`-th (1-origin) target bit if exists. Else returns None.
+    ///
+    /// `words` and `length` must be the ones given to [new()](#method.new).
+    pub fn select(&self, words: &[u64], length: u64, num: u64) -> Option<u64> {
+        if num == 0 || num > self.count {
+            return None;
+        }
+        let k = num - 1;
+        let sample = self.samples[(k / SAMPLE_INTERVAL) as usize];
+        let mut rest = k % SAMPLE_INTERVAL;
+
+        if sample & LONG_BLOCK_FLAG != 0 {
+            let block = &self.long_blocks[(sample & !LONG_BLOCK_FLAG) as usize];
+            let offset = self.read_offset(block.start + rest * block.width, block.width);
+            return Some(block.first + offset);
+        }
+
+        // Scan words from the block's first target, which is in `i_word`.
+        let mut i_word = (sample / 64) as usize;
+        let mut word = self.target_word(words, length, i_word) & (!0u64 << (sample % 64));
+        loop {
+            let cnt = word.count_ones() as u64;
+            if rest < cnt {
+                for _ in 0..rest {
+                    word &= word - 1;
+                }
+                return Some(i_word as u64 * 64 + word.trailing_zeros() as u64);
+            }
+            rest -= cnt;
+            i_word += 1;
+            word = self.target_word(words, length, i_word);
+        }
+    }
+
+    /// Returns `i_word`-th word where target bits are '1', masking bits beyond `length`.
+    fn target_word(&self, words: &[u64], length: u64, i_word: usize) -> u64 {
+        let word = if self.bit {
+            words[i_word]
+        } else {
+            !words[i_word]
+        };
+        if i_word + 1 == RawBitVector::calc_words_cnt(length) {
+            word & RawBitVector::last_word_mask(length)
+        } else {
+            word
+        }
+    }
+
+    /// Appends a block of (up to `SAMPLE_INTERVAL`) target positions.
+    fn push_block(&mut self, block: &[u64]) {
+        let (first, last) = (block[0], block[block.len() - 1]);
+        if last - first >= LONG_BLOCK_SPAN {
+            self.samples
+                .push(LONG_BLOCK_FLAG | self.long_blocks.len() as u64);
+            let width = 64 - (last - first).leading_zeros() as u64;
+            self.long_blocks.push(LongBlock {
+                first,
+                width,
+                start: self.long_offsets_len,
+            });
+            for &position in block {
+                self.push_offset(position - first, width);
+            }
+        } else {
+            self.samples.push(first);
+        }
+        self.count += block.len() as u64;
+    }
+
+    /// Appends `offset` (< _2^`width`_) to `long_offsets` as a `width`-bit field.
+    fn push_offset(&mut self, offset: u64, width: u64) {
+        let shift = self.long_offsets_len % 64;
+        if shift == 0 {
+            self.long_offsets.push(offset);
+        } else {
+            *self.long_offsets.last_mut().unwrap() |= offset << shift;
+            if shift + width > 64 {
+                self.long_offsets.push(offset >> (64 - shift));
+            }
+        }
+        self.long_offsets_len += width;
+    }
+
+    /// Reads the `width`-bit field at bit `pos` of `long_offsets`.
+    fn read_offset(&self, pos: u64, width: u64) -> u64 {
+        let (i, shift) = ((pos / 64) as usize, pos % 64);
+        let mut offset = self.long_offsets[i] >> shift;
+        if shift + width > 64 {
+            offset |= self.long_offsets[i + 1] << (64 - shift);
+        }
+        if width < 64 {
+            offset & ((1 << width) - 1)
+        } else {
+            offset
+        }
+    }
+}
+
+#[cfg(test)]
+mod select_success_tests {
+    use super::SelectIndex;
+    use crate::internal_data_structure::raw_bit_vector::RawBitVector;
+    use crate::BitString;
+
+    /// Positions of `bit` by scanning: `positions[num - 1]` is the answer of select(`num`).
+    fn naive_positions(s: &str, bit: bool) -> Vec<u64> {
+        let target = if bit { '1' } else { '0' };
+        s.char_indices()
+            .filter(|&(_, c)| c == target)
+            .map(|(i, _)| i as u64)
+            .collect()
+    }
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let in_s: String = $value;
+                let bs = BitString::new(&in_s);
+                let rbv = RawBitVector::from_bit_string(&bs);
+                let (words, length) = (rbv.words().as_slice(), rbv.length());
+
+                for &bit in &[true, false] {
+                    let index = SelectIndex::new(words, length, bit);
+                    let positions = naive_positions(bs.str(), bit);
+                    assert_eq!(index.select(words, length, 0), None);
+                    for (i, &position) in positions.iter().enumerate() {
+                        assert_eq!(index.select(words, length, i as u64 + 1), Some(position), "bit = {}", bit);
+                    }
+                    assert_eq!(index.select(words, length, positions.len() as u64 + 1), None);
+                }
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: "0".to_string(),
+        t2: "1".to_string(),
+        t3: "1000_1001".to_string(),
+        t4: "10_1110_10_0_1110_0_0_10_110_0_0_0".to_string(),
+
+        word_boundary_1: "1".repeat(64) + "0",
+        word_boundary_2: "0".repeat(63) + "1" + &"0".repeat(64),
+        word_boundary_3: "01".repeat(200),
+
+        dense_blocks: "110".repeat(1000),
+        long_blocks: ("1".to_string() + &"0".repeat(100)).repeat(300),
+        very_long_blocks: ("1".to_string() + &"0".repeat(1000)).repeat(300),
+        long_and_dense_blocks: "1".repeat(300) + &"0".repeat(20000) + &"1".repeat(300) + &("1".to_string() + &"0".repeat(70)).repeat(200),
+    }
+}
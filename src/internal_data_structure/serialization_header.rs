@@ -0,0 +1,62 @@
+//! Header of succinct_rs' own serialization format.
+//!
+//! ```text
+//! | magic "SCRS" (4 bytes) | version (u8) | kind (u8) | flags (u8) | reserved (u8) |
+//! ```
+
+use super::binary_io::{invalid_data, read_u8, write_u8};
+use std::io::{self, Read, Write};
+
+const MAGIC: &[u8; 4] = b"SCRS";
+const VERSION: u8 = 1;
+
+/// Flag telling the rank/select directory follows the raw bits. The loader rebuilds the directory when absent.
+pub const FLAG_DIRECTORY: u8 = 0b0000_0001;
+
+/// Data structure serialized after the header.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SerializedKind {
+    SuccinctBitVector = 0,
+    Louds = 1,
+}
+
+pub fn write_header<W: Write>(w: &mut W, kind: SerializedKind, flags: u8) -> io::Result<()> {
+    w.write_all(MAGIC)?;
+    write_u8(w, VERSION)?;
+    write_u8(w, kind as u8)?;
+    write_u8(w, flags)?;
+    write_u8(w, 0)
+}
+
+/// Reads the header and returns its flags.
+///
+/// # Errors
+/// When the header is broken, of unknown version or flags, or of another kind than `kind`.
+pub fn read_header<R: Read>(r: &mut R, kind: SerializedKind) -> io::Result<u8> {
+    let mut magic = [0u8; 4];
+    r.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(invalid_data(format!("unknown magic {:?}", magic)));
+    }
+
+    let version = read_u8(r)?;
+    if version != VERSION {
+        return Err(invalid_data(format!("unsupported version {}", version)));
+    }
+
+    let actual_kind = read_u8(r)?;
+    if actual_kind != kind as u8 {
+        return Err(invalid_data(format!(
+            "expected {:?} (kind {}) but kind {} is serialized",
+            kind, kind as u8, actual_kind
+        )));
+    }
+
+    let flags = read_u8(r)?;
+    if flags & !FLAG_DIRECTORY != 0 {
+        return Err(invalid_data(format!("unknown flags {:#010b}", flags)));
+    }
+
+    let _reserved = read_u8(r)?;
+    Ok(flags)
+}
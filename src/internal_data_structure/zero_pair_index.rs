@@ -0,0 +1,155 @@
+use super::raw_bit_vector::RawBitVector;
+
+/// The number of words per block, whose preceding occurrences are counted in the directory.
+const BLOCK_WORDS: usize = 16;
+
+#[derive(Clone)]
+/// Rank/select of the pattern "00" over raw words, without materializing where it occurs.
+///
+/// An occurrence is identified by the position of its second '0', so occurrences may overlap ("000" has two, at 1 and 2).
+/// Occurrences in a word are found by a few bit operations on the word and the last bit of the previous word.
+/// The directory keeps a cumulative count per `BLOCK_WORDS` words, which takes _1 / `BLOCK_WORDS`_ bits per bit.
+pub struct ZeroPairIndex {
+    /// `block_counts[b]` is the number of occurrences in the blocks before `b`-th, followed by the total number.
+    block_counts: Vec<u64>,
+}
+
+impl ZeroPairIndex {
+    /// Builds the directory over the first `length` bits of `words` in _O(N)_ time.
+    ///
+    /// The `i`-th bit is the _(i % 64)_-th least significant bit of _(i / 64)_-th word.
+    pub fn new(words: &[u64], length: u64) -> Self {
+        let words_cnt = RawBitVector::calc_words_cnt(length);
+        let mut block_counts = Vec::with_capacity(words_cnt / BLOCK_WORDS + 2);
+        let mut count = 0;
+        for i_word in 0..words_cnt {
+            if i_word % BLOCK_WORDS == 0 {
+                block_counts.push(count);
+            }
+            count += Self::pattern_word(words, length, i_word).count_ones() as u64;
+        }
+        block_counts.push(count);
+        ZeroPairIndex { block_counts }
+    }
+
+    /// Returns the number of occurrences.
+    pub fn count(&self) -> u64 {
+        self.block_counts[self.block_counts.len() - 1]
+    }
+
+    /// Returns the number of occurrences ending in _[0, `i`]_.
+    ///
+    /// `words` and `length` must be the ones given to [new()](#method.new).
+    pub fn rank(&self, words: &[u64], length: u64, i: u64) -> u64 {
+        let i_word = (i / 64) as usize;
+        let block_start = i_word - i_word % BLOCK_WORDS;
+        let mut rank = self.block_counts[block_start / BLOCK_WORDS];
+        for j in block_start..i_word {
+            rank += Self::pattern_word(words, length, j).count_ones() as u64;
+        }
+        let mask = (!0u64) >> (63 - i % 64);
+        rank + (Self::pattern_word(words, length, i_word) & mask).count_ones() as u64
+    }
+
+    /// Returns the position (0-origin) where the `num`-th (1-origin) occurrence ends if exists. Else returns None.
+    ///
+    /// `words` and `length` must be the ones given to [new()](#method.new).
+    pub fn select(&self, words: &[u64], length: u64, num: u64) -> Option<u64> {
+        if num == 0 || num > self.count() {
+            return None;
+        }
+        // The last block whose preceding occurrences are fewer than `num`.
+        let block = self.block_counts.partition_point(|&cnt| cnt < num) - 1;
+        let mut rest = num - 1 - self.block_counts[block];
+        let mut i_word = block * BLOCK_WORDS;
+        loop {
+            let mut word = Self::pattern_word(words, length, i_word);
+            let cnt = word.count_ones() as u64;
+            if rest < cnt {
+                for _ in 0..rest {
+                    word &= word - 1;
+                }
+                return Some(i_word as u64 * 64 + word.trailing_zeros() as u64);
+            }
+            rest -= cnt;
+            i_word += 1;
+        }
+    }
+
+    /// Returns `i_word`-th word where '1' marks the end of an occurrence, masking bits beyond `length`.
+    fn pattern_word(words: &[u64], length: u64, i_word: usize) -> u64 {
+        // The previous word's last bit; no occurrence ends at position 0.
+        let carry = if i_word == 0 {
+            1
+        } else {
+            words[i_word - 1] >> 63
+        };
+        let word = !words[i_word] & !((words[i_word] << 1) | carry);
+        if i_word + 1 == RawBitVector::calc_words_cnt(length) {
+            word & RawBitVector::last_word_mask(length)
+        } else {
+            word
+        }
+    }
+}
+
+#[cfg(test)]
+mod rank_select_success_tests {
+    use super::ZeroPairIndex;
+    use crate::internal_data_structure::raw_bit_vector::RawBitVector;
+    use crate::BitString;
+
+    /// Positions where "00" ends by scanning.
+    fn naive_positions(s: &str) -> Vec<u64> {
+        let bytes = s.as_bytes();
+        (1..bytes.len())
+            .filter(|&i| bytes[i - 1] == b'0' && bytes[i] == b'0')
+            .map(|i| i as u64)
+            .collect()
+    }
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let in_s: String = $value;
+                let bs = BitString::new(&in_s);
+                let rbv = RawBitVector::from_bit_string(&bs);
+                let (words, length) = (rbv.words().as_slice(), rbv.length());
+                let index = ZeroPairIndex::new(words, length);
+                let positions = naive_positions(bs.str());
+
+                assert_eq!(index.count(), positions.len() as u64);
+                let mut expected_rank = 0;
+                for i in 0..length {
+                    if positions.get(expected_rank as usize) == Some(&i) {
+                        expected_rank += 1;
+                    }
+                    assert_eq!(index.rank(words, length, i), expected_rank, "i = {}", i);
+                }
+
+                assert_eq!(index.select(words, length, 0), None);
+                for (k, &position) in positions.iter().enumerate() {
+                    assert_eq!(index.select(words, length, k as u64 + 1), Some(position));
+                }
+                assert_eq!(index.select(words, length, positions.len() as u64 + 1), None);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: "0".to_string(),
+        t2: "00".to_string(),
+        t3: "000".to_string(),
+        t4: "10_1110_10_0_1110_0_0_10_110_0_0_0".to_string(),
+
+        word_boundary_1: "1".repeat(63) + "00",
+        word_boundary_2: "1".repeat(64) + "0",
+        word_boundary_3: "0".repeat(64) + "1" + &"0".repeat(64),
+
+        many_blocks_1: "100".repeat(2000),
+        many_blocks_2: "0".repeat(3000) + &"1".repeat(3000) + "00",
+    }
+}
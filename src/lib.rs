@@ -5,10 +5,18 @@
 //! See [README](https://github.com/laysakura/succinct.rs/blob/master/README.md) for more about usage and features.
 
 pub use bit_string::BitString;
-pub use louds::{Louds, LoudsBuilder, LoudsIndex, LoudsNodeNum};
+pub use louds::{
+    LabeledLouds, LabeledLoudsEdit, Louds, LoudsAncestors, LoudsBuilder, LoudsChildren,
+    LoudsDescendants, LoudsEdit, LoudsEdited, LoudsForest, LoudsForestBuilder, LoudsForestRoots,
+    LoudsIndex, LoudsNodeNum, LoudsTraversal, LoudsTraversalWithDepth,
+};
+pub use louds_trie::{LoudsTrie, LoudsTrieBuilder};
+pub use ordinal_tree::{OrdinalChildren, OrdinalTree};
 pub use succinct_bit_vector::{SuccinctBitVector, SuccinctBitVectorBuilder};
 
 pub mod bit_string;
 mod internal_data_structure;
 pub mod louds;
+pub mod louds_trie;
+pub mod ordinal_tree;
 pub mod succinct_bit_vector;
@@ -1,9 +1,25 @@
+mod labeled_louds;
+#[allow(clippy::module_inception)]
 mod louds;
+mod louds_ancestors;
 mod louds_builder;
+mod louds_children;
+mod louds_descendants;
+mod louds_edit;
+mod louds_encoding;
+mod louds_forest;
+mod louds_forest_builder;
 mod louds_index;
 mod louds_node_num;
+mod louds_ordinal_tree;
+mod louds_serialization;
+mod louds_subtree;
+mod louds_traversal;
 
+use crate::internal_data_structure::select_index::SelectIndex;
+use crate::internal_data_structure::zero_pair_index::ZeroPairIndex;
 use crate::{SuccinctBitVector, SuccinctBitVectorBuilder};
+use std::collections::{HashMap, HashSet};
 
 /// LOUDS (Level-Order Unary Degree Sequence).
 ///
@@ -72,17 +88,62 @@ use crate::{SuccinctBitVector, SuccinctBitVectorBuilder};
 ///
 /// // LoudsNodeNum <-> LoudsIndex
 /// let node8 = LoudsNodeNum::new(8);
-/// let index11 = louds.node_num_to_index(&node8);
-/// assert_eq!(louds.index_to_node_num(&index11), node8);
+/// let index11 = louds.node_num_to_index(node8);
+/// assert_eq!(louds.index_to_node_num(index11), node8);
 ///
 /// // Search for children.
-/// assert_eq!(louds.parent_to_children(&node8), vec!(LoudsIndex::new(17), LoudsIndex::new(18)));
+/// assert_eq!(louds.parent_to_children(node8), vec!(LoudsIndex::new(17), LoudsIndex::new(18)));
 ///
 /// // Search for parent.
-/// assert_eq!(louds.child_to_parent(&index11), LoudsNodeNum::new(4));
+/// assert_eq!(louds.child_to_parent(index11), LoudsNodeNum::new(4));
 /// ```
+///
+/// # Passing NodeNums and indices
+/// [LoudsNodeNum](struct.LoudsNodeNum.html) and [LoudsIndex](struct.LoudsIndex.html) are `Copy` and methods take them by value.
+/// Passing them by reference (e.g. `louds.degree(&node_num)`) still compiles as in older versions, but is deprecated and will stop compiling in v0.8.0.
 pub struct Louds {
     lbs: SuccinctBitVector,
+
+    /// Samples to `select()` '1's of LBS in _O(1)_, used to find nodes' indices.
+    select1_samples: SelectIndex,
+
+    /// Samples to `select0()` '0's of LBS in _O(1)_, used to find where nodes' children start and end.
+    select0_samples: SelectIndex,
+
+    /// `level_starts[d]` is the NodeNum of the leftmost node at depth _d_, followed by a sentinel _(the number of nodes) + 1_.
+    ///
+    /// Nodes at the same depth have consecutive NodeNums in level order, so these boundaries tell each node's depth.
+    level_starts: Vec<u64>,
+
+    /// Rank/select of "00" in LBS. A node is a leaf iff its '0' ends "00", so leaves are counted by this.
+    leaves: ZeroPairIndex,
+}
+
+/// [Louds](struct.Louds.html) with one label (or any value) per node.
+///
+/// Labels are stored in NodeNum order. Since siblings have consecutive NodeNums, their labels form a contiguous range,
+/// where a child can be found by its label with binary search.
+///
+/// # Examples
+/// ```
+/// use succinct_rs::{BitString, LabeledLouds, LoudsBuilder, LoudsNodeNum};
+///
+/// //    (1) "/"
+/// //     |---------+
+/// //    (2) "bin" (3) "usr"
+/// //               |
+/// //              (4) "lib"
+/// let louds = LoudsBuilder::from_bit_string(BitString::new("10_110_0_10_0")).build();
+/// let fs = LabeledLouds::new(louds, vec!["/", "bin", "usr", "lib"]);
+///
+/// let usr = fs.child_by_label(LoudsNodeNum::new(1), &"usr").unwrap();
+/// assert_eq!(usr, LoudsNodeNum::new(3));
+/// assert_eq!(fs.label(LoudsNodeNum::new(4)), &"lib");
+/// assert_eq!(fs.child_by_label(usr, &"local"), None);
+/// ```
+pub struct LabeledLouds<T> {
+    louds: Louds,
+    labels: Vec<T>,
 }
 
 /// The builder of [Louds](struct.Louds.html).
@@ -90,14 +151,140 @@ pub struct LoudsBuilder {
     bv_builder: SuccinctBitVectorBuilder,
 }
 
-#[derive(PartialEq, Eq, Debug)]
+/// Accumulated structural edits on a [Louds](struct.Louds.html), created by [Louds::edit()](struct.Louds.html#method.edit).
+///
+/// Edits refer to the NodeNums of the original tree. [apply()](struct.LoudsEdit.html#method.apply) builds the edited tree in a single level-order pass.
+pub struct LoudsEdit<'a> {
+    louds: &'a Louds,
+
+    /// `(parent, position, insertion number)` of each inserted leaf.
+    inserts: Vec<(u64, u64, usize)>,
+
+    /// Roots of removed subtrees.
+    removed: HashSet<u64>,
+}
+
+/// Accumulated edits on a [LabeledLouds](struct.LabeledLouds.html), created by [LabeledLouds::edit()](struct.LabeledLouds.html#method.edit).
+pub struct LabeledLoudsEdit<'a, T> {
+    labeled: &'a LabeledLouds<T>,
+    edit: LoudsEdit<'a>,
+    inserted_labels: Vec<T>,
+    relabels: HashMap<u64, T>,
+}
+
+/// The tree built by applying edits, with the mapping from the original NodeNums to the new ones.
+pub struct LoudsEdited<L> {
+    tree: L,
+
+    /// `old_to_new[k - 1]` is the new NodeNum of original node#k.
+    old_to_new: Vec<Option<u64>>,
+
+    /// `inserted[i]` is the NodeNum of the leaf inserted by insertion number _i_.
+    inserted: Vec<Option<u64>>,
+}
+
+/// Forest (ordered list of trees) in LOUDS.
+///
+/// The LBS of the forest is the concatenation of each tree's LBS, so the nodes of a tree have consecutive NodeNums
+/// and a tree can be handled independently (e.g. one tree per document).
+///
+/// NodeNums are global in the forest: the first tree has node#1, node#2, ..., node#_n_, and the second tree starts with node#_(n + 1)_.
+///
+/// # Examples
+/// ```
+/// use succinct_rs::{BitString, LoudsForestBuilder, LoudsNodeNum};
+///
+/// // (1)      (4)
+/// //  |---+    |
+/// // (2) (3)  (5)
+/// let forest = LoudsForestBuilder::from_bit_string(BitString::new("10_110_0_0_10_10_0")).build();
+///
+/// assert_eq!(forest.roots().map(|r| r.value()).collect::<Vec<u64>>(), vec!(1, 4));
+/// assert_eq!(forest.tree_of(LoudsNodeNum::new(5)), 1);
+/// assert_eq!(forest.tree_node_nums(1), LoudsNodeNum::new(4)..LoudsNodeNum::new(6));
+/// assert_eq!(forest.parent(LoudsNodeNum::new(5)), Some(LoudsNodeNum::new(4)));
+/// assert_eq!(forest.parent(LoudsNodeNum::new(4)), None);
+///
+/// // Each tree can be taken out as a `Louds`, whose NodeNums start from 1.
+/// assert_eq!(format!("{}", forest.tree(1)), "10_10_0 (nodes: 2)");
+/// ```
+pub struct LoudsForest {
+    lbs: SuccinctBitVector,
+
+    /// `tree_starts[t]` is the NodeNum of _t_-th tree's root, followed by a sentinel _(the number of nodes) + 1_.
+    tree_starts: Vec<u64>,
+}
+
+/// The builder of [LoudsForest](struct.LoudsForest.html).
+pub struct LoudsForestBuilder {
+    bv_builder: SuccinctBitVectorBuilder,
+}
+
+/// Iterator over the roots of a [LoudsForest](struct.LoudsForest.html), created by [LoudsForest::roots()](struct.LoudsForest.html#method.roots).
+pub struct LoudsForestRoots<'a> {
+    tree_starts: std::slice::Iter<'a, u64>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 /// Node number of [Louds](struct.Louds.html) tree.
+///
+/// Converts from / into `u64` as is with `From` and `Into`. Ordered by the value.
 pub struct LoudsNodeNum {
     value: u64,
 }
 
-#[derive(PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 /// Index of [Louds](struct.Louds.html) tree.
+///
+/// Converts from / into `u64` as is with `From` and `Into`. Ordered by the value.
 pub struct LoudsIndex {
     value: u64,
 }
+
+/// Iterator over the children of a [Louds](struct.Louds.html) node, created by [Louds::children()](struct.Louds.html#method.children).
+pub struct LoudsChildren<'a> {
+    lbs: &'a SuccinctBitVector,
+    next: u64,
+}
+
+/// Iterator over the ancestors of a [Louds](struct.Louds.html) node, created by [Louds::ancestors()](struct.Louds.html#method.ancestors).
+pub struct LoudsAncestors<'a> {
+    louds: &'a Louds,
+    node_num: u64,
+}
+
+/// Iterator over the descendants of a [Louds](struct.Louds.html) node in level order, created by [Louds::descendants()](struct.Louds.html#method.descendants).
+pub struct LoudsDescendants<'a> {
+    louds: &'a Louds,
+
+    /// NodeNum range _[level_start, level_end)_ of the descendants at the current depth.
+    level_start: u64,
+    level_end: u64,
+
+    next: u64,
+}
+
+/// Iterator over all the nodes of a [Louds](struct.Louds.html) in breadth-first or depth-first order.
+///
+/// Created by [Louds::bfs()](struct.Louds.html#method.bfs), [Louds::dfs_preorder()](struct.Louds.html#method.dfs_preorder) or [Louds::dfs_postorder()](struct.Louds.html#method.dfs_postorder).
+pub struct LoudsTraversal<'a> {
+    louds: &'a Louds,
+    state: TraversalState,
+}
+
+/// Iterator yielding each node with its depth, created by [LoudsTraversal::with_depth()](struct.LoudsTraversal.html#method.with_depth).
+pub struct LoudsTraversalWithDepth<'a> {
+    traversal: LoudsTraversal<'a>,
+}
+
+/// Explicit stack (or cursor) of [LoudsTraversal](struct.LoudsTraversal.html).
+enum TraversalState {
+    /// NodeNums are level-ordered: just count up. `depth` is the level of `next`.
+    Bfs { next: u64, depth: u64 },
+
+    /// Each entry is the NodeNum range of unvisited siblings and their depth.
+    PreOrder { stack: Vec<(u64, u64, u64)> },
+
+    /// Each entry is a node on the current path, its depth and the NodeNum range of its unvisited children.
+    PostOrder { stack: Vec<(u64, u64, u64, u64)> },
+}
@@ -0,0 +1,224 @@
+use super::{LabeledLouds, Louds, LoudsNodeNum};
+use std::borrow::Borrow;
+
+impl<T> LabeledLouds<T> {
+    /// Attaches `labels` to `louds`: `labels[k - 1]` is the label of node#k.
+    ///
+    /// # Panics
+    /// When the number of `labels` differs from the number of nodes in `louds`.
+    pub fn new(louds: Louds, labels: Vec<T>) -> Self {
+        assert_eq!(
+            labels.len() as u64,
+            louds.nodes_cnt(),
+            "{} labels are given for {} nodes",
+            labels.len(),
+            louds.nodes_cnt()
+        );
+        Self { louds, labels }
+    }
+
+    /// Returns the underlying tree.
+    pub fn louds(&self) -> &Louds {
+        &self.louds
+    }
+
+    /// Returns all the labels in NodeNum order.
+    pub fn labels(&self) -> &[T] {
+        &self.labels
+    }
+
+    /// Returns the tree and the labels.
+    pub fn into_parts(self) -> (Louds, Vec<T>) {
+        (self.louds, self.labels)
+    }
+
+    /// Returns the label of `node_num`.
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this LOUDS.
+    pub fn label(&self, node_num: impl Borrow<LoudsNodeNum>) -> &T {
+        let node_num = node_num.borrow();
+        self.louds.validate_node_num(node_num);
+        &self.labels[node_num.value() as usize - 1]
+    }
+
+    /// Returns the labels of `node_num`'s children from left to right.
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this LOUDS.
+    pub fn children_labels(&self, node_num: impl Borrow<LoudsNodeNum>) -> &[T] {
+        let node_num = node_num.borrow();
+        let (first, end) = self.louds.children_node_nums(node_num.value());
+        &self.labels[first as usize - 1..end as usize - 1]
+    }
+}
+
+impl<T: Ord> LabeledLouds<T> {
+    /// Returns the child of `node_num` labeled `label` if exists.
+    ///
+    /// It binary-searches the contiguous labels of the children, so the children must be sorted by their labels; otherwise the result is unspecified.
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this LOUDS.
+    pub fn child_by_label(
+        &self,
+        node_num: impl Borrow<LoudsNodeNum>,
+        label: &T,
+    ) -> Option<LoudsNodeNum> {
+        let node_num = node_num.borrow();
+        let (first, end) = self.louds.children_node_nums(node_num.value());
+        self.labels[first as usize - 1..end as usize - 1]
+            .binary_search(label)
+            .ok()
+            .map(|k| LoudsNodeNum::new(first + k as u64))
+    }
+}
+
+#[cfg(test)]
+mod new_failure_tests {
+    use crate::{BitString, LabeledLouds, LoudsBuilder};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, labels): (&str, Vec<u64>) = $value;
+                let louds = LoudsBuilder::from_bit_string(BitString::new(in_s)).build();
+                let _ = LabeledLouds::new(louds, labels);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", vec!()),
+        t1_2: ("10_0", vec!(1, 2)),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10)),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12)),
+    }
+}
+
+#[cfg(test)]
+mod label_success_tests {
+    use crate::{BitString, LabeledLouds, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, labels, node_num, expected_label, expected_children_labels): (&str, Vec<&str>, u64, &str, Vec<&str>) = $value;
+                let louds = LoudsBuilder::from_bit_string(BitString::new(in_s)).build();
+                let labeled = LabeledLouds::new(louds, labels);
+                let node_num = LoudsNodeNum::new(node_num);
+                assert_eq!(labeled.label(node_num), &expected_label);
+                assert_eq!(labeled.children_labels(node_num), &expected_children_labels[..]);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", vec!("a"), 1, "a", vec!()),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!("a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k"), 1, "a", vec!("b", "c", "d")),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!("a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k"), 2, "b", vec!("e")),
+        t3_3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!("a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k"), 3, "c", vec!()),
+        t3_4: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!("a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k"), 4, "d", vec!("f", "g", "h")),
+        t3_5: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!("a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k"), 8, "h", vec!("j", "k")),
+        t3_6: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!("a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k"), 11, "k", vec!()),
+    }
+}
+
+#[cfg(test)]
+mod label_failure_tests {
+    use crate::{BitString, LabeledLouds, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_node_not_found_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, labels, node_num): (&str, Vec<u64>, u64) = $value;
+                let louds = LoudsBuilder::from_bit_string(BitString::new(in_s)).build();
+                let labeled = LabeledLouds::new(louds, labels);
+                let _ = labeled.label(LoudsNodeNum::new(node_num));
+            }
+        )*
+        }
+    }
+
+    parameterized_node_not_found_tests! {
+        t1_1: ("10_0", vec!(1), 0),
+        t1_2: ("10_0", vec!(1), 2),
+    }
+}
+
+#[cfg(test)]
+mod child_by_label_success_tests {
+    use crate::{BitString, LabeledLouds, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, labels, node_num, label, expected_child): (&str, Vec<char>, u64, char, Option<u64>) = $value;
+                let louds = LoudsBuilder::from_bit_string(BitString::new(in_s)).build();
+                let labeled = LabeledLouds::new(louds, labels);
+                let child = labeled.child_by_label(LoudsNodeNum::new(node_num), &label);
+                assert_eq!(child, expected_child.map(LoudsNodeNum::new));
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", vec!('a'), 1, 'a', None),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!('-', 'b', 'd', 'f', 'x', 'a', 'c', 'e', 'x', 'p', 'q'), 1, 'a', None),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!('-', 'b', 'd', 'f', 'x', 'a', 'c', 'e', 'x', 'p', 'q'), 1, 'b', Some(2)),
+        t3_3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!('-', 'b', 'd', 'f', 'x', 'a', 'c', 'e', 'x', 'p', 'q'), 1, 'c', None),
+        t3_4: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!('-', 'b', 'd', 'f', 'x', 'a', 'c', 'e', 'x', 'p', 'q'), 1, 'd', Some(3)),
+        t3_5: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!('-', 'b', 'd', 'f', 'x', 'a', 'c', 'e', 'x', 'p', 'q'), 1, 'f', Some(4)),
+        t3_6: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!('-', 'b', 'd', 'f', 'x', 'a', 'c', 'e', 'x', 'p', 'q'), 1, 'x', None),
+        t3_7: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!('-', 'b', 'd', 'f', 'x', 'a', 'c', 'e', 'x', 'p', 'q'), 2, 'x', Some(5)),
+        t3_8: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!('-', 'b', 'd', 'f', 'x', 'a', 'c', 'e', 'x', 'p', 'q'), 3, 'x', None),
+        t3_9: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!('-', 'b', 'd', 'f', 'x', 'a', 'c', 'e', 'x', 'p', 'q'), 4, 'a', Some(6)),
+        t3_10: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!('-', 'b', 'd', 'f', 'x', 'a', 'c', 'e', 'x', 'p', 'q'), 4, 'c', Some(7)),
+        t3_11: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!('-', 'b', 'd', 'f', 'x', 'a', 'c', 'e', 'x', 'p', 'q'), 4, 'e', Some(8)),
+        t3_12: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!('-', 'b', 'd', 'f', 'x', 'a', 'c', 'e', 'x', 'p', 'q'), 4, 'b', None),
+        t3_13: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!('-', 'b', 'd', 'f', 'x', 'a', 'c', 'e', 'x', 'p', 'q'), 8, 'p', Some(10)),
+        t3_14: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!('-', 'b', 'd', 'f', 'x', 'a', 'c', 'e', 'x', 'p', 'q'), 8, 'q', Some(11)),
+        t3_15: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!('-', 'b', 'd', 'f', 'x', 'a', 'c', 'e', 'x', 'p', 'q'), 11, 'q', None),
+    }
+}
+
+#[cfg(test)]
+mod child_by_label_failure_tests {
+    use crate::{BitString, LabeledLouds, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_node_not_found_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, labels, node_num): (&str, Vec<u64>, u64) = $value;
+                let louds = LoudsBuilder::from_bit_string(BitString::new(in_s)).build();
+                let labeled = LabeledLouds::new(louds, labels);
+                let _ = labeled.child_by_label(LoudsNodeNum::new(node_num), &1);
+            }
+        )*
+        }
+    }
+
+    parameterized_node_not_found_tests! {
+        t1_1: ("10_0", vec!(1), 0),
+        t1_2: ("10_0", vec!(1), 2),
+    }
+}
@@ -1,22 +1,49 @@
-use super::{Louds, LoudsIndex, LoudsNodeNum};
+use super::{Louds, LoudsAncestors, LoudsChildren, LoudsIndex, LoudsNodeNum};
+use crate::internal_data_structure::select_index::SelectIndex;
+use crate::internal_data_structure::zero_pair_index::ZeroPairIndex;
+use crate::SuccinctBitVector;
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Range;
+
+/// The number of leading LBS bits shown by `Debug` and `Display`.
+const FMT_LEADING_BITS: u64 = 64;
 
 impl Louds {
+    /// Constructor from a valid LBS. Computes the select samples and the level boundaries.
+    pub(super) fn from_lbs(lbs: SuccinctBitVector) -> Self {
+        let select1_samples = SelectIndex::new(lbs.storage(), lbs.length(), true);
+        let select0_samples = SelectIndex::new(lbs.storage(), lbs.length(), false);
+        let leaves = ZeroPairIndex::new(lbs.storage(), lbs.length());
+        let mut louds = Louds {
+            lbs,
+            select1_samples,
+            select0_samples,
+            level_starts: vec![],
+            leaves,
+        };
+        louds.level_starts = louds.calc_level_starts();
+        louds
+    }
+
     /// # Panics
     /// `node_num` does not exist in this LOUDS.
-    pub fn node_num_to_index(&self, node_num: &LoudsNodeNum) -> LoudsIndex {
+    pub fn node_num_to_index(&self, node_num: impl Borrow<LoudsNodeNum>) -> LoudsIndex {
+        let node_num = node_num.borrow();
         assert!(node_num.value() > 0);
 
-        let index = self.lbs.select(node_num.value()).expect(&format!(
-            "NodeNum({}) does not exist in this LOUDS",
-            node_num.value(),
-        ));
+        let index = self.lbs_select(node_num.value()).unwrap_or_else(|| {
+            panic!("NodeNum({}) does not exist in this LOUDS", node_num.value(),)
+        });
         LoudsIndex::new(index)
     }
 
     /// # Panics
     /// `index` does not point to any node in this LOUDS.
-    pub fn index_to_node_num(&self, index: &LoudsIndex) -> LoudsNodeNum {
-        self.validate_index(&index);
+    pub fn index_to_node_num(&self, index: impl Borrow<LoudsIndex>) -> LoudsNodeNum {
+        let index = index.borrow();
+        self.validate_index(index);
 
         let node_num = self.lbs.rank(index.value());
         LoudsNodeNum::new(node_num)
@@ -25,8 +52,9 @@ impl Louds {
     /// # Panics
     /// - `index` does not point to any node in this LOUDS.
     /// - `index == 0`: (node#1 is root and doesn't have parent)
-    pub fn child_to_parent(&self, index: &LoudsIndex) -> LoudsNodeNum {
-        self.validate_index(&index);
+    pub fn child_to_parent(&self, index: impl Borrow<LoudsIndex>) -> LoudsNodeNum {
+        let index = index.borrow();
+        self.validate_index(index);
         assert!(index.value != 0, "node#1 is root and doesn't have parent");
 
         let parent_node_num = self.lbs.rank0(index.value());
@@ -35,40 +63,595 @@ impl Louds {
 
     /// # Panics
     /// `node_num` does not exist in this LOUDS.
-    pub fn parent_to_children(&self, node_num: &LoudsNodeNum) -> Vec<LoudsIndex> {
-        assert!(node_num.value() > 0);
+    pub fn parent_to_children(&self, node_num: impl Borrow<LoudsNodeNum>) -> Vec<LoudsIndex> {
+        let node_num = node_num.borrow();
+        self.children(node_num).collect()
+    }
 
-        let parent_start_index = self.lbs.select0(node_num.value()).expect(&format!(
-            "NodeNum({}) does not exist in this LOUDS",
-            node_num.value(),
-        )) + 1;
+    /// Returns an iterator over the indices of `node_num`'s children, from left to right.
+    ///
+    /// Unlike [parent_to_children()](#method.parent_to_children), it does not allocate: one `select0` locates the first child and each step reads one bit.
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this LOUDS.
+    pub fn children(&self, node_num: impl Borrow<LoudsNodeNum>) -> LoudsChildren<'_> {
+        let node_num = node_num.borrow();
+        LoudsChildren::new(&self.lbs, self.children_start(node_num))
+    }
+
+    /// Returns the number of children of `node_num`.
+    ///
+    /// It takes two `select0`: the degree is the length of the unary code between `node_num`'s '0' and the next '0'.
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this LOUDS.
+    pub fn degree(&self, node_num: impl Borrow<LoudsNodeNum>) -> u64 {
+        let node_num = node_num.borrow();
+        let start = self.children_start(node_num);
+        let end = self.children_end(node_num);
+        end - start
+    }
+
+    /// Returns the index of the leftmost child of `node_num` if exists.
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this LOUDS.
+    pub fn first_child(&self, node_num: impl Borrow<LoudsNodeNum>) -> Option<LoudsIndex> {
+        let node_num = node_num.borrow();
+        let start = self.children_start(node_num);
+        if self.lbs.access(start) {
+            Some(LoudsIndex::new(start))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the index of the rightmost child of `node_num` if exists.
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this LOUDS.
+    pub fn last_child(&self, node_num: impl Borrow<LoudsNodeNum>) -> Option<LoudsIndex> {
+        let node_num = node_num.borrow();
+        // LBS[end - 1] is either the last child ('1') or `node_num`'s own '0' when it has no child.
+        let end = self.children_end(node_num);
+        if self.lbs.access(end - 1) {
+            Some(LoudsIndex::new(end - 1))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the index of the `k`-th (0-origin) child of `node_num` if exists.
+    ///
+    /// It takes one `select0`: the candidate index is checked to be '1' and to lie before the next '0' with `rank0`.
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this LOUDS.
+    pub fn child(&self, node_num: impl Borrow<LoudsNodeNum>, k: u64) -> Option<LoudsIndex> {
+        let node_num = node_num.borrow();
+        let i = self.children_start(node_num).checked_add(k)?;
+        if i < self.lbs.length() && self.lbs.access(i) && self.lbs.rank0(i) == node_num.value() {
+            Some(LoudsIndex::new(i))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the index of the next (right) sibling of `index` if exists.
+    ///
+    /// It takes _O(1)_ time: the next sibling is the next '1' in LBS, unless the parent's unary degree ends there ('0').
+    ///
+    /// # Panics
+    /// `index` does not point to any node in this LOUDS.
+    pub fn next_sibling(&self, index: impl Borrow<LoudsIndex>) -> Option<LoudsIndex> {
+        let index = index.borrow();
+        self.validate_index(index);
+
+        let next = index.value() + 1;
+        if next < self.lbs.length() && self.lbs.access(next) {
+            Some(LoudsIndex::new(next))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the index of the previous (left) sibling of `index` if exists.
+    ///
+    /// It takes _O(1)_ time: the previous sibling is the previous '1' in LBS, unless the parent's unary degree starts there.
+    ///
+    /// # Panics
+    /// `index` does not point to any node in this LOUDS.
+    pub fn prev_sibling(&self, index: impl Borrow<LoudsIndex>) -> Option<LoudsIndex> {
+        let index = index.borrow();
+        self.validate_index(index);
+
+        let value = index.value();
+        if value > 0 && self.lbs.access(value - 1) {
+            Some(LoudsIndex::new(value - 1))
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether `node_num` has no child.
+    ///
+    /// It takes one `select0` and one `access`: a leaf's '0' is immediately followed by the next node's '0'.
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this LOUDS.
+    pub fn is_leaf(&self, node_num: impl Borrow<LoudsNodeNum>) -> bool {
+        let node_num = node_num.borrow();
+        !self.lbs.access(self.children_start(node_num))
+    }
+
+    /// Returns whether `node_num` is the root (node#1).
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this LOUDS.
+    pub fn is_root(&self, node_num: impl Borrow<LoudsNodeNum>) -> bool {
+        let node_num = node_num.borrow();
+        self.validate_node_num(node_num);
+        node_num.value() == 1
+    }
+
+    /// Returns the number of leaves preceding `node_num` in level order, i.e. leaves among node#1 ..= node#_(`node_num` - 1)_.
+    ///
+    /// Leaves are numbered densely from 0 by this: `leaf_select(leaf_rank(leaf)) == Some(leaf)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use succinct_rs::{BitString, LoudsBuilder, LoudsNodeNum};
+    ///
+    /// let louds = LoudsBuilder::from_bit_string(BitString::new("10_1110_10_0_1110_0_0_10_110_0_0_0")).build();
+    ///
+    /// // Leaves are node#3, #5, #6, #9, #10 and #11.
+    /// assert_eq!(louds.leaf_rank(LoudsNodeNum::new(3)), 0);
+    /// assert_eq!(louds.leaf_rank(LoudsNodeNum::new(4)), 1);
+    /// assert_eq!(louds.leaf_rank(LoudsNodeNum::new(9)), 3);
+    /// assert_eq!(louds.leaf_select(3), Some(LoudsNodeNum::new(9)));
+    /// assert_eq!(louds.leaf_select(6), None);
+    /// ```
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this LOUDS.
+    pub fn leaf_rank(&self, node_num: impl Borrow<LoudsNodeNum>) -> u64 {
+        let node_num = node_num.borrow();
+        self.validate_node_num(node_num);
+        // The '0' of node#(node_num - 1) (or of the virtual root).
+        let prev_zero = self.lbs_select0(node_num.value()).unwrap();
+        self.leaves
+            .rank(self.lbs.storage(), self.lbs.length(), prev_zero)
+    }
+
+    /// Returns the `k`-th (0-origin) leaf in level order if exists. Else returns None.
+    ///
+    /// It finds the end of the _(`k` + 1)_-th "00" in LBS, which is the leaf's '0'.
+    pub fn leaf_select(&self, k: u64) -> Option<LoudsNodeNum> {
+        let leaf_zero = self
+            .leaves
+            .select(self.lbs.storage(), self.lbs.length(), k + 1)?;
+        Some(LoudsNodeNum::new(self.lbs.rank0(leaf_zero) - 1))
+    }
+
+    /// Returns the depth of `node_num`; the root's depth is 0.
+    ///
+    /// It binary-searches the level boundaries computed at build time and takes _O(log H)_ time where _H_ is the height of the tree.
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this LOUDS.
+    pub fn depth(&self, node_num: impl Borrow<LoudsNodeNum>) -> u64 {
+        let node_num = node_num.borrow();
+        self.validate_node_num(node_num);
+        match self.level_starts.binary_search(&node_num.value()) {
+            Ok(d) => d as u64,
+            Err(d) => d as u64 - 1,
+        }
+    }
+
+    /// Returns the height of the tree: the maximum depth of its nodes. A tree with only the root has height 0.
+    pub fn height(&self) -> u64 {
+        // Excluding the sentinel.
+        self.level_starts.len() as u64 - 2
+    }
 
-        let mut children_index: Vec<u64> = vec![];
-        let mut i = parent_start_index;
-        loop {
-            if self.lbs.access(i) == false {
+    /// Returns the NodeNums of the nodes at depth `d`: nodes at the same depth have consecutive NodeNums.
+    ///
+    /// It looks up the level boundaries computed at build time in _O(1)_.
+    ///
+    /// # Examples
+    /// ```
+    /// use succinct_rs::{BitString, LoudsBuilder, LoudsNodeNum};
+    ///
+    /// let louds = LoudsBuilder::from_bit_string(BitString::new("10_1110_10_0_1110_0_0_10_110_0_0_0")).build();
+    ///
+    /// assert_eq!(louds.level_range(1), LoudsNodeNum::new(2)..LoudsNodeNum::new(5));
+    /// assert_eq!(louds.level_width(2), 4);
+    /// assert_eq!(louds.level_of(LoudsNodeNum::new(9)), 3);
+    /// ```
+    ///
+    /// # Panics
+    /// _`d` > [height()](#method.height)_.
+    pub fn level_range(&self, d: u64) -> Range<LoudsNodeNum> {
+        self.validate_depth(d);
+        let d = d as usize;
+        LoudsNodeNum::new(self.level_starts[d])..LoudsNodeNum::new(self.level_starts[d + 1])
+    }
+
+    /// Returns the number of nodes at depth `d` in _O(1)_.
+    ///
+    /// # Panics
+    /// _`d` > [height()](#method.height)_.
+    pub fn level_width(&self, d: u64) -> u64 {
+        self.validate_depth(d);
+        let d = d as usize;
+        self.level_starts[d + 1] - self.level_starts[d]
+    }
+
+    /// Returns the level (depth) `node_num` belongs to, such that `level_range(level_of(node_num))` contains `node_num`.
+    ///
+    /// Same as [depth()](#method.depth), taking _O(log H)_ time where _H_ is the height of the tree.
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this LOUDS.
+    pub fn level_of(&self, node_num: impl Borrow<LoudsNodeNum>) -> u64 {
+        let node_num = node_num.borrow();
+        self.depth(node_num)
+    }
+
+    /// Returns an iterator over the ancestors of `node_num`: its parent, grandparent, ... and finally the root.
+    ///
+    /// Each step takes one `select` and one `rank0` ([child_to_parent()](#method.child_to_parent)).
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this LOUDS.
+    pub fn ancestors(&self, node_num: impl Borrow<LoudsNodeNum>) -> LoudsAncestors<'_> {
+        let node_num = node_num.borrow();
+        self.validate_node_num(node_num);
+        LoudsAncestors::new(self, node_num.value())
+    }
+
+    /// Returns the `k`-th ancestor of `node_num` if exists: `node_num` itself when _`k` == 0_, its parent when _`k` == 1_, ...
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this LOUDS.
+    pub fn level_ancestor(
+        &self,
+        node_num: impl Borrow<LoudsNodeNum>,
+        k: u64,
+    ) -> Option<LoudsNodeNum> {
+        let node_num = node_num.borrow();
+        if k > self.depth(node_num) {
+            return None;
+        }
+        if k == 0 {
+            return Some(LoudsNodeNum::new(node_num.value()));
+        }
+        self.ancestors(node_num).nth(k as usize - 1)
+    }
+
+    /// Returns the path from the root to `node_num` as child ranks: _k_-th element is the (0-origin) position of the _(k + 1)_-th node on the path among its siblings.
+    ///
+    /// The root's path is empty. [node_at_path()](#method.node_at_path) is the inverse.
+    ///
+    /// # Examples
+    /// ```
+    /// use succinct_rs::{BitString, LoudsBuilder, LoudsNodeNum};
+    ///
+    /// let louds = LoudsBuilder::from_bit_string(BitString::new("10_1110_10_0_1110_0_0_10_110_0_0_0")).build();
+    /// assert_eq!(louds.path_from_root(LoudsNodeNum::new(10)), vec!(2, 2, 0));
+    /// assert_eq!(louds.node_at_path(&[2, 2, 0]), Some(LoudsNodeNum::new(10)));
+    /// ```
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this LOUDS.
+    pub fn path_from_root(&self, node_num: impl Borrow<LoudsNodeNum>) -> Vec<usize> {
+        let node_num = node_num.borrow();
+        self.validate_node_num(node_num);
+
+        let mut path = vec![];
+        let mut index = self.node_num_to_index(node_num);
+        while index.value() != 0 {
+            let parent = self.child_to_parent(index);
+            path.push((index.value() - self.children_start(&parent)) as usize);
+            index = self.node_num_to_index(parent);
+        }
+        path.reverse();
+        path
+    }
+
+    /// Returns the node reached by following child ranks in `path` from the root, if exists.
+    ///
+    /// The empty path points to the root. [path_from_root()](#method.path_from_root) is the inverse.
+    pub fn node_at_path(&self, path: &[usize]) -> Option<LoudsNodeNum> {
+        let mut node_num = LoudsNodeNum::new(1);
+        for k in path {
+            let index = self.child(node_num, *k as u64)?;
+            node_num = self.index_to_node_num(index);
+        }
+        Some(node_num)
+    }
+
+    /// Returns the lowest common ancestor of `a` and `b`: the deepest node having both as descendants (a node is a descendant of itself).
+    ///
+    /// It lifts the deeper node up to the other's depth, then climbs both in lockstep; it takes _O(H)_ parent steps where _H_ is the height of the tree.
+    ///
+    /// # Examples
+    /// ```
+    /// use succinct_rs::{BitString, LoudsBuilder, LoudsNodeNum};
+    ///
+    /// let louds = LoudsBuilder::from_bit_string(BitString::new("10_1110_10_0_1110_0_0_10_110_0_0_0")).build();
+    /// assert_eq!(louds.lca(LoudsNodeNum::new(9), LoudsNodeNum::new(11)), LoudsNodeNum::new(4));
+    /// assert_eq!(louds.lca(LoudsNodeNum::new(5), LoudsNodeNum::new(2)), LoudsNodeNum::new(2));
+    /// ```
+    ///
+    /// # Panics
+    /// `a` or `b` does not exist in this LOUDS.
+    pub fn lca(&self, a: impl Borrow<LoudsNodeNum>, b: impl Borrow<LoudsNodeNum>) -> LoudsNodeNum {
+        let a = a.borrow();
+        let b = b.borrow();
+        let (depth_a, depth_b) = (self.depth(a), self.depth(b));
+        let (mut a, mut b) = if depth_a > depth_b {
+            (self.level_ancestor(a, depth_a - depth_b).unwrap(), *b)
+        } else {
+            (*a, self.level_ancestor(b, depth_b - depth_a).unwrap())
+        };
+        while a != b {
+            a = self.parent_of(a);
+            b = self.parent_of(b);
+        }
+        a
+    }
+
+    /// Returns [lca()](#method.lca) of each pair in `pairs`.
+    ///
+    /// Parent steps are cached across pairs, so the ancestors shared by many pairs (typically the ones near the root) are computed once.
+    /// Depths are not needed either: NodeNums are in BFS order, so climbing from the larger NodeNum of a pair never passes their LCA.
+    ///
+    /// # Panics
+    /// A node in `pairs` does not exist in this LOUDS.
+    pub fn lca_batch(&self, pairs: &[(LoudsNodeNum, LoudsNodeNum)]) -> Vec<LoudsNodeNum> {
+        let mut parents: HashMap<LoudsNodeNum, LoudsNodeNum> = HashMap::new();
+        pairs
+            .iter()
+            .map(|&(a, b)| {
+                self.validate_node_num(&a);
+                self.validate_node_num(&b);
+                let (mut a, mut b) = (a, b);
+                while a != b {
+                    let deeper = if a > b { &mut a } else { &mut b };
+                    *deeper = *parents
+                        .entry(*deeper)
+                        .or_insert_with(|| self.parent_of(*deeper));
+                }
+                a
+            })
+            .collect()
+    }
+
+    /// Renders the tree in [Graphviz](https://graphviz.org/) DOT language.
+    ///
+    /// Each node is labeled with its NodeNum and, in angle brackets, its LoudsIndex.
+    ///
+    /// ```
+    /// use succinct_rs::{BitString, LoudsBuilder};
+    ///
+    /// let louds = LoudsBuilder::from_bit_string(BitString::new("10_110_0_0")).build();
+    /// assert_eq!(
+    ///     louds.to_dot(),
+    ///     "digraph louds {
+    ///     1 [label=\"1\\n<0>\"];
+    ///     2 [label=\"2\\n<2>\"];
+    ///     3 [label=\"3\\n<3>\"];
+    ///     1 -> 2;
+    ///     1 -> 3;
+    /// }
+    /// "
+    /// );
+    /// ```
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph louds {\n");
+        for node_num in 1..=self.nodes_cnt() {
+            let index = self.node_num_to_index(LoudsNodeNum::new(node_num));
+            dot.push_str(&format!(
+                "    {} [label=\"{}\\n<{}>\"];\n",
+                node_num,
+                node_num,
+                index.value()
+            ));
+        }
+        for node_num in 1..=self.nodes_cnt() {
+            for child_index in self.parent_to_children(LoudsNodeNum::new(node_num)) {
+                let child = self.index_to_node_num(child_index);
+                dot.push_str(&format!("    {} -> {};\n", node_num, child.value()));
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Renders the tree as ASCII art; each line shows (NodeNum) and <LoudsIndex>.
+    ///
+    /// It walks the tree with an explicit stack, so deep trees do not overflow the call stack.
+    ///
+    /// ```
+    /// use succinct_rs::{BitString, LoudsBuilder};
+    ///
+    /// let louds = LoudsBuilder::from_bit_string(BitString::new("10_110_10_0_0")).build();
+    /// assert_eq!(
+    ///     louds.to_ascii_tree(),
+    ///     "(1) <0>
+    /// |-- (2) <2>
+    /// |   `-- (4) <5>
+    /// `-- (3) <3>
+    /// "
+    /// );
+    /// ```
+    pub fn to_ascii_tree(&self) -> String {
+        let mut tree = String::new();
+
+        // (node, leading string of its line, prefix of its children's lines)
+        let mut stack = vec![(LoudsNodeNum::new(1), String::new(), String::new())];
+        while let Some((node_num, lead, prefix)) = stack.pop() {
+            let index = self.node_num_to_index(node_num);
+            tree.push_str(&format!(
+                "{}({}) <{}>\n",
+                lead,
+                node_num.value(),
+                index.value()
+            ));
+
+            let children = self.parent_to_children(node_num);
+            let n_children = children.len();
+            for (i, child_index) in children.iter().enumerate().rev() {
+                let (connector, indent) = if i + 1 == n_children {
+                    ("`-- ", "    ")
+                } else {
+                    ("|-- ", "|   ")
+                };
+                stack.push((
+                    self.index_to_node_num(child_index),
+                    format!("{}{}", prefix, connector),
+                    format!("{}{}", prefix, indent),
+                ));
+            }
+        }
+        tree
+    }
+
+    /// Returns the parent of non-root `node_num`.
+    fn parent_of(&self, node_num: LoudsNodeNum) -> LoudsNodeNum {
+        self.child_to_parent(self.node_num_to_index(node_num))
+    }
+
+    /// Returns the index right after `node_num`'s '0', where its unary degree starts.
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this LOUDS.
+    pub(super) fn children_start(&self, node_num: &LoudsNodeNum) -> u64 {
+        self.validate_node_num(node_num);
+        self.lbs_select0(node_num.value()).unwrap() + 1
+    }
+
+    /// Returns the index of the '0' terminating `node_num`'s unary degree.
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this LOUDS.
+    pub(super) fn children_end(&self, node_num: &LoudsNodeNum) -> u64 {
+        self.validate_node_num(node_num);
+        self.lbs_select0(node_num.value() + 1).unwrap()
+    }
+
+    /// Computes `level_starts` level by level.
+    ///
+    /// When the level ends with node#_b_, its children (i.e. the next level) end with node#_rank(select0(b + 1))_, since LBS up to _b_'s '0' holds a '1' for the root and for each child of node#1 ..= node#_b_.
+    ///
+    /// A tree has fewer levels than LBS has bits, so the loop is bounded even if a corrupted LBS never reaches a last level.
+    fn calc_level_starts(&self) -> Vec<u64> {
+        let mut level_starts = vec![1];
+        let mut level_end = 1;
+        for _ in 0..self.lbs.length() {
+            let next_level_end = self.lbs.rank(self.lbs_select0(level_end + 1).unwrap());
+            level_starts.push(level_end + 1);
+            if next_level_end == level_end {
                 break;
+            }
+            level_end = next_level_end;
+        }
+        level_starts
+    }
+
+    /// Same as `self.lbs.select(num)` for _`num` > 0_, in _O(1)_ with the samples.
+    fn lbs_select(&self, num: u64) -> Option<u64> {
+        self.select1_samples
+            .select(self.lbs.storage(), self.lbs.length(), num)
+    }
+
+    /// Same as `self.lbs.select0(num)` for _`num` > 0_, in _O(1)_ with the samples.
+    fn lbs_select0(&self, num: u64) -> Option<u64> {
+        self.select0_samples
+            .select(self.lbs.storage(), self.lbs.length(), num)
+    }
+
+    /// Returns the number of nodes (excluding the virtual root).
+    pub(super) fn nodes_cnt(&self) -> u64 {
+        self.lbs.rank(self.lbs.length() - 1)
+    }
+
+    /// Returns up to `FMT_LEADING_BITS` leading bits of LBS, separated by '_' after each node's '0', followed by "..." if truncated.
+    fn leading_lbs(&self) -> String {
+        let n = self.lbs.length();
+        let mut s = String::new();
+        for i in 0..n.min(FMT_LEADING_BITS) {
+            if self.lbs.access(i) {
+                s.push('1');
             } else {
-                children_index.push(i);
+                s.push('0');
+                if i + 1 < n {
+                    s.push('_');
+                }
             }
-            i += 1;
         }
+        if n > FMT_LEADING_BITS {
+            s.push_str("...");
+        }
+        s
+    }
+
+    /// # Panics
+    /// `node_num` does not exist in this LOUDS.
+    pub(super) fn validate_node_num(&self, node_num: &LoudsNodeNum) {
+        assert!(
+            node_num.value() > 0 && node_num.value() <= self.nodes_cnt(),
+            "NodeNum({}) does not exist in this LOUDS",
+            node_num.value(),
+        );
+    }
 
-        children_index.iter().map(|i| LoudsIndex::new(*i)).collect()
+    /// # Panics
+    /// _`d` > height_.
+    fn validate_depth(&self, d: u64) {
+        assert!(
+            d <= self.height(),
+            "Depth {} exceeds the height {} of this LOUDS",
+            d,
+            self.height()
+        );
     }
 
     /// # Panics
     /// `index` does not point to any node in this LOUDS.
     fn validate_index(&self, index: &LoudsIndex) {
-        assert_eq!(
+        assert!(
             self.lbs.access(index.value()),
-            true,
             "LBS[index={:?}] must be '1'",
             index,
         );
     }
 }
 
+impl fmt::Debug for Louds {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Louds")
+            .field("nodes", &self.nodes_cnt())
+            .field("lbs", &self.leading_lbs())
+            .finish()
+    }
+}
+
+/// Shows the leading LBS (separated by '_' after each node) and the number of nodes.
+///
+/// ```
+/// use succinct_rs::{BitString, LoudsBuilder};
+///
+/// let louds = LoudsBuilder::from_bit_string(BitString::new("10_110_0_0")).build();
+/// assert_eq!(format!("{}", louds), "10_110_0_0 (nodes: 3)");
+/// ```
+impl fmt::Display for Louds {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (nodes: {})", self.leading_lbs(), self.nodes_cnt())
+    }
+}
+
 #[cfg(test)]
 mod node_num_to_index_success_tests {
     use crate::{BitString, LoudsBuilder, LoudsIndex, LoudsNodeNum};
@@ -81,7 +664,7 @@ mod node_num_to_index_success_tests {
                 let (in_s, node_num, expected_index) = $value;
                 let bs = BitString::new(in_s);
                 let louds = LoudsBuilder::from_bit_string(bs).build();
-                let index = louds.node_num_to_index(&LoudsNodeNum::new(node_num));
+                let index = louds.node_num_to_index(LoudsNodeNum::new(node_num));
                 assert_eq!(index, LoudsIndex::new(expected_index));
             }
         )*
@@ -106,7 +689,6 @@ mod node_num_to_index_success_tests {
         t3_10: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 10, 17),
         t3_11: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 11, 18),
     }
-
 }
 
 #[cfg(test)]
@@ -122,7 +704,7 @@ mod node_num_to_index_failure_tests {
                 let (in_s, node_num) = $value;
                 let bs = BitString::new(in_s);
                 let louds = LoudsBuilder::from_bit_string(bs).build();
-                let _ = louds.node_num_to_index(&LoudsNodeNum::new(node_num));
+                let _ = louds.node_num_to_index(LoudsNodeNum::new(node_num));
             }
         )*
         }
@@ -152,7 +734,7 @@ mod index_to_node_num_success_tests {
                 let (in_s, index, expected_node_num) = $value;
                 let bs = BitString::new(in_s);
                 let louds = LoudsBuilder::from_bit_string(bs).build();
-                let node_num = louds.index_to_node_num(&LoudsIndex::new(index));
+                let node_num = louds.index_to_node_num(LoudsIndex::new(index));
                 assert_eq!(node_num, LoudsNodeNum::new(expected_node_num));
             }
         )*
@@ -177,7 +759,6 @@ mod index_to_node_num_success_tests {
         t3_10: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 17, 10),
         t3_11: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 18, 11),
     }
-
 }
 
 #[cfg(test)]
@@ -193,7 +774,7 @@ mod index_to_node_num_failure_tests {
                 let (in_s, index) = $value;
                 let bs = BitString::new(in_s);
                 let louds = LoudsBuilder::from_bit_string(bs).build();
-                let _ = louds.index_to_node_num(&LoudsIndex::new(index));
+                let _ = louds.index_to_node_num(LoudsIndex::new(index));
             }
         )*
         }
@@ -237,7 +818,7 @@ mod child_to_parent_success_tests {
                 let (in_s, index, expected_parent) = $value;
                 let bs = BitString::new(in_s);
                 let louds = LoudsBuilder::from_bit_string(bs).build();
-                let parent = louds.child_to_parent(&LoudsIndex::new(index));
+                let parent = louds.child_to_parent(LoudsIndex::new(index));
                 assert_eq!(parent, LoudsNodeNum::new(expected_parent));
             }
         )*
@@ -273,7 +854,7 @@ mod child_to_parent_failure_tests {
                 let (in_s, index) = $value;
                 let bs = BitString::new(in_s);
                 let louds = LoudsBuilder::from_bit_string(bs).build();
-                let _ = louds.child_to_parent(&LoudsIndex::new(index));
+                let _ = louds.child_to_parent(LoudsIndex::new(index));
             }
         )*
         }
@@ -313,7 +894,7 @@ mod child_to_parent_failure_tests {
                 let in_s = $value;
                 let bs = BitString::new(in_s);
                 let louds = LoudsBuilder::from_bit_string(bs).build();
-                let _ = louds.child_to_parent(&LoudsIndex::new(0));
+                let _ = louds.child_to_parent(LoudsIndex::new(0));
             }
         )*
         }
@@ -338,7 +919,7 @@ mod parent_to_children_success_tests {
                 let (in_s, node_num, expected_children) = $value;
                 let bs = BitString::new(in_s);
                 let louds = LoudsBuilder::from_bit_string(bs).build();
-                let children = louds.parent_to_children(&LoudsNodeNum::new(node_num));
+                let children = louds.parent_to_children(LoudsNodeNum::new(node_num));
                 assert_eq!(children, expected_children.iter().map(|c| LoudsIndex::new(*c)).collect::<Vec<LoudsIndex>>());
             }
         )*
@@ -363,7 +944,6 @@ mod parent_to_children_success_tests {
         t3_10: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 10, vec!()),
         t3_11: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 11, vec!()),
     }
-
 }
 
 #[cfg(test)]
@@ -379,7 +959,77 @@ mod parent_to_children_failure_tests {
                 let (in_s, node_num) = $value;
                 let bs = BitString::new(in_s);
                 let louds = LoudsBuilder::from_bit_string(bs).build();
-                let _ = louds.parent_to_children(&LoudsNodeNum::new(node_num));
+                let _ = louds.parent_to_children(LoudsNodeNum::new(node_num));
+            }
+        )*
+        }
+    }
+
+    parameterized_node_not_found_tests! {
+        t1_1: ("10_0", 0),
+        t1_2: ("10_0", 2),
+
+        t2_1: ("10_10_0", 0),
+        t2_2: ("10_10_0", 3),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 0),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 12),
+    }
+}
+
+#[cfg(test)]
+mod children_success_tests {
+    use crate::{BitString, LoudsBuilder, LoudsIndex, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, node_num, expected_children) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let children: Vec<LoudsIndex> = louds.children(LoudsNodeNum::new(node_num)).collect();
+                assert_eq!(children, expected_children.iter().map(|c| LoudsIndex::new(*c)).collect::<Vec<LoudsIndex>>());
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", 1, vec!()),
+
+        t2_1: ("10_10_0", 1, vec!(2)),
+        t2_2: ("10_10_0", 2, vec!()),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, vec!(2, 3, 4)),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, vec!(6)),
+        t3_3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, vec!()),
+        t3_4: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, vec!(9, 10, 11)),
+        t3_5: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 5, vec!()),
+        t3_6: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 6, vec!()),
+        t3_7: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 7, vec!(15)),
+        t3_8: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 8, vec!(17, 18)),
+        t3_9: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 9, vec!()),
+        t3_10: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 10, vec!()),
+        t3_11: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 11, vec!()),
+    }
+}
+
+#[cfg(test)]
+mod children_failure_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_node_not_found_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, node_num) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let _ = louds.children(LoudsNodeNum::new(node_num));
             }
         )*
         }
@@ -396,3 +1046,1346 @@ mod parent_to_children_failure_tests {
         t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 12),
     }
 }
+
+#[cfg(test)]
+mod degree_success_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, node_num, expected_degree) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let degree = louds.degree(LoudsNodeNum::new(node_num));
+                assert_eq!(degree, expected_degree);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", 1, 0),
+
+        t2_1: ("10_10_0", 1, 1),
+        t2_2: ("10_10_0", 2, 0),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, 3),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, 1),
+        t3_3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, 0),
+        t3_4: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, 3),
+        t3_5: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 5, 0),
+        t3_6: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 6, 0),
+        t3_7: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 7, 1),
+        t3_8: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 8, 2),
+        t3_9: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 9, 0),
+        t3_10: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 10, 0),
+        t3_11: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 11, 0),
+    }
+}
+
+#[cfg(test)]
+mod degree_failure_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_node_not_found_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, node_num) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let _ = louds.degree(LoudsNodeNum::new(node_num));
+            }
+        )*
+        }
+    }
+
+    parameterized_node_not_found_tests! {
+        t1_1: ("10_0", 0),
+        t1_2: ("10_0", 2),
+
+        t2_1: ("10_10_0", 0),
+        t2_2: ("10_10_0", 3),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 0),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 12),
+    }
+}
+
+#[cfg(test)]
+mod first_child_success_tests {
+    use crate::{BitString, LoudsBuilder, LoudsIndex, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, node_num, expected_child) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let child = louds.first_child(LoudsNodeNum::new(node_num));
+                assert_eq!(child, expected_child.map(LoudsIndex::new));
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", 1, None),
+
+        t2_1: ("10_10_0", 1, Some(2)),
+        t2_2: ("10_10_0", 2, None),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, Some(2)),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, Some(6)),
+        t3_3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, None),
+        t3_4: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, Some(9)),
+        t3_5: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 5, None),
+        t3_6: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 6, None),
+        t3_7: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 7, Some(15)),
+        t3_8: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 8, Some(17)),
+        t3_9: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 9, None),
+        t3_10: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 10, None),
+        t3_11: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 11, None),
+    }
+}
+
+#[cfg(test)]
+mod first_child_failure_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_node_not_found_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, node_num) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let _ = louds.first_child(LoudsNodeNum::new(node_num));
+            }
+        )*
+        }
+    }
+
+    parameterized_node_not_found_tests! {
+        t1_1: ("10_0", 0),
+        t1_2: ("10_0", 2),
+
+        t2_1: ("10_10_0", 0),
+        t2_2: ("10_10_0", 3),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 0),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 12),
+    }
+}
+
+#[cfg(test)]
+mod last_child_success_tests {
+    use crate::{BitString, LoudsBuilder, LoudsIndex, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, node_num, expected_child) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let child = louds.last_child(LoudsNodeNum::new(node_num));
+                assert_eq!(child, expected_child.map(LoudsIndex::new));
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", 1, None),
+
+        t2_1: ("10_10_0", 1, Some(2)),
+        t2_2: ("10_10_0", 2, None),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, Some(4)),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, Some(6)),
+        t3_3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, None),
+        t3_4: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, Some(11)),
+        t3_5: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 5, None),
+        t3_6: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 6, None),
+        t3_7: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 7, Some(15)),
+        t3_8: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 8, Some(18)),
+        t3_9: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 9, None),
+        t3_10: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 10, None),
+        t3_11: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 11, None),
+    }
+}
+
+#[cfg(test)]
+mod last_child_failure_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_node_not_found_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, node_num) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let _ = louds.last_child(LoudsNodeNum::new(node_num));
+            }
+        )*
+        }
+    }
+
+    parameterized_node_not_found_tests! {
+        t1_1: ("10_0", 0),
+        t1_2: ("10_0", 2),
+
+        t2_1: ("10_10_0", 0),
+        t2_2: ("10_10_0", 3),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 0),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 12),
+    }
+}
+
+#[cfg(test)]
+mod child_success_tests {
+    use crate::{BitString, LoudsBuilder, LoudsIndex, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, node_num, k, expected_child) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let child = louds.child(LoudsNodeNum::new(node_num), k);
+                assert_eq!(child, expected_child.map(LoudsIndex::new));
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", 1, 0, None),
+
+        t2_1: ("10_10_0", 1, 0, Some(2)),
+        t2_2: ("10_10_0", 1, 1, None),
+        t2_3: ("10_10_0", 2, 0, None),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, 0, Some(2)),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, 1, Some(3)),
+        t3_3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, 2, Some(4)),
+        t3_4: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, 3, None),
+        t3_5: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, 0, Some(6)),
+        t3_6: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, 1, None),
+        t3_7: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, 0, None),
+        t3_8: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, 0, Some(9)),
+        t3_9: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, 1, Some(10)),
+        t3_10: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, 2, Some(11)),
+        t3_11: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, 3, None),
+        t3_12: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 5, 0, None),
+        t3_13: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 6, 0, None),
+        t3_14: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 7, 0, Some(15)),
+        t3_15: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 7, 1, None),
+        t3_16: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 8, 0, Some(17)),
+        t3_17: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 8, 1, Some(18)),
+        t3_18: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 8, 2, None),
+        t3_19: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 9, 0, None),
+        t3_20: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 10, 0, None),
+        t3_21: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 11, 0, None),
+        t3_22: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, u64::MAX, None),
+        t3_23: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 11, u64::MAX, None),
+    }
+}
+
+#[cfg(test)]
+mod child_failure_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_node_not_found_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, node_num) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let _ = louds.child(LoudsNodeNum::new(node_num), 0);
+            }
+        )*
+        }
+    }
+
+    parameterized_node_not_found_tests! {
+        t1_1: ("10_0", 0),
+        t1_2: ("10_0", 2),
+
+        t2_1: ("10_10_0", 0),
+        t2_2: ("10_10_0", 3),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 0),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 12),
+    }
+}
+
+#[cfg(test)]
+mod is_leaf_success_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, node_num, expected) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let is_leaf = louds.is_leaf(LoudsNodeNum::new(node_num));
+                assert_eq!(is_leaf, expected);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", 1, true),
+
+        t2_1: ("10_10_0", 1, false),
+        t2_2: ("10_10_0", 2, true),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, false),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, false),
+        t3_3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, true),
+        t3_4: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, false),
+        t3_5: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 5, true),
+        t3_6: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 6, true),
+        t3_7: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 7, false),
+        t3_8: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 8, false),
+        t3_9: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 9, true),
+        t3_10: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 10, true),
+        t3_11: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 11, true),
+    }
+}
+
+#[cfg(test)]
+mod is_leaf_failure_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_node_not_found_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, node_num) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let _ = louds.is_leaf(LoudsNodeNum::new(node_num));
+            }
+        )*
+        }
+    }
+
+    parameterized_node_not_found_tests! {
+        t1_1: ("10_0", 0),
+        t1_2: ("10_0", 2),
+
+        t2_1: ("10_10_0", 0),
+        t2_2: ("10_10_0", 3),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 0),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 12),
+    }
+}
+
+#[cfg(test)]
+mod is_root_success_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, node_num, expected) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let is_root = louds.is_root(LoudsNodeNum::new(node_num));
+                assert_eq!(is_root, expected);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", 1, true),
+
+        t2_1: ("10_10_0", 1, true),
+        t2_2: ("10_10_0", 2, false),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, true),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, false),
+        t3_3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, false),
+        t3_4: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, false),
+        t3_5: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 5, false),
+        t3_6: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 6, false),
+        t3_7: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 7, false),
+        t3_8: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 8, false),
+        t3_9: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 9, false),
+        t3_10: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 10, false),
+        t3_11: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 11, false),
+    }
+}
+
+#[cfg(test)]
+mod is_root_failure_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_node_not_found_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, node_num) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let _ = louds.is_root(LoudsNodeNum::new(node_num));
+            }
+        )*
+        }
+    }
+
+    parameterized_node_not_found_tests! {
+        t1_1: ("10_0", 0),
+        t1_2: ("10_0", 2),
+
+        t2_1: ("10_10_0", 0),
+        t2_2: ("10_10_0", 3),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 0),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 12),
+    }
+}
+
+#[cfg(test)]
+mod depth_success_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, node_num, expected_depth) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let depth = louds.depth(LoudsNodeNum::new(node_num));
+                assert_eq!(depth, expected_depth);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", 1, 0),
+
+        t2_1: ("10_10_0", 1, 0),
+        t2_2: ("10_10_0", 2, 1),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, 0),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, 1),
+        t3_3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, 1),
+        t3_4: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, 1),
+        t3_5: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 5, 2),
+        t3_6: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 6, 2),
+        t3_7: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 7, 2),
+        t3_8: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 8, 2),
+        t3_9: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 9, 3),
+        t3_10: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 10, 3),
+        t3_11: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 11, 3),
+    }
+}
+
+#[cfg(test)]
+mod depth_failure_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_node_not_found_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, node_num) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let _ = louds.depth(LoudsNodeNum::new(node_num));
+            }
+        )*
+        }
+    }
+
+    parameterized_node_not_found_tests! {
+        t1_1: ("10_0", 0),
+        t1_2: ("10_0", 2),
+
+        t2_1: ("10_10_0", 0),
+        t2_2: ("10_10_0", 3),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 0),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 12),
+    }
+}
+
+#[cfg(test)]
+mod height_success_tests {
+    use crate::{BitString, LoudsBuilder};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, expected_height) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                assert_eq!(louds.height(), expected_height);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: ("10_0", 0),
+        t2: ("10_10_0", 1),
+        t3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3),
+        t4: ("10_11111111110_0_0_0_0_0_0_0_0_0_0", 1),
+        t5: ("10_10_10_10_10_0", 4),
+    }
+}
+
+#[cfg(test)]
+mod ancestors_success_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, node_num, expected_ancestors): (&str, u64, Vec<u64>) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let ancestors: Vec<u64> = louds.ancestors(LoudsNodeNum::new(node_num)).map(|n| n.value()).collect();
+                assert_eq!(ancestors, expected_ancestors);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", 1, vec!()),
+
+        t2_1: ("10_10_0", 1, vec!()),
+        t2_2: ("10_10_0", 2, vec!(1)),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, vec!()),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, vec!(1)),
+        t3_3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, vec!(1)),
+        t3_4: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, vec!(1)),
+        t3_5: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 5, vec!(2, 1)),
+        t3_6: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 6, vec!(4, 1)),
+        t3_7: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 7, vec!(4, 1)),
+        t3_8: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 8, vec!(4, 1)),
+        t3_9: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 9, vec!(7, 4, 1)),
+        t3_10: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 10, vec!(8, 4, 1)),
+        t3_11: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 11, vec!(8, 4, 1)),
+    }
+}
+
+#[cfg(test)]
+mod ancestors_failure_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_node_not_found_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, node_num) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let _ = louds.ancestors(LoudsNodeNum::new(node_num));
+            }
+        )*
+        }
+    }
+
+    parameterized_node_not_found_tests! {
+        t1_1: ("10_0", 0),
+        t1_2: ("10_0", 2),
+
+        t2_1: ("10_10_0", 0),
+        t2_2: ("10_10_0", 3),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 0),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 12),
+    }
+}
+
+#[cfg(test)]
+mod level_ancestor_success_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, node_num, k, expected_ancestor) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let ancestor = louds.level_ancestor(LoudsNodeNum::new(node_num), k);
+                assert_eq!(ancestor, expected_ancestor);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", 1, 0, Some(LoudsNodeNum::new(1))),
+        t1_2: ("10_0", 1, 1, None),
+
+        t2_1: ("10_10_0", 1, 0, Some(LoudsNodeNum::new(1))),
+        t2_2: ("10_10_0", 1, 1, None),
+        t2_3: ("10_10_0", 2, 0, Some(LoudsNodeNum::new(2))),
+        t2_4: ("10_10_0", 2, 1, Some(LoudsNodeNum::new(1))),
+        t2_5: ("10_10_0", 2, 2, None),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, 0, Some(LoudsNodeNum::new(1))),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, 1, None),
+        t3_3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, 0, Some(LoudsNodeNum::new(2))),
+        t3_4: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, 1, Some(LoudsNodeNum::new(1))),
+        t3_5: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, 2, None),
+        t3_6: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, 0, Some(LoudsNodeNum::new(3))),
+        t3_7: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, 1, Some(LoudsNodeNum::new(1))),
+        t3_8: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, 2, None),
+        t3_9: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, 0, Some(LoudsNodeNum::new(4))),
+        t3_10: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, 1, Some(LoudsNodeNum::new(1))),
+        t3_11: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, 2, None),
+        t3_12: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 5, 0, Some(LoudsNodeNum::new(5))),
+        t3_13: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 5, 1, Some(LoudsNodeNum::new(2))),
+        t3_14: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 5, 2, Some(LoudsNodeNum::new(1))),
+        t3_15: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 5, 3, None),
+        t3_16: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 6, 0, Some(LoudsNodeNum::new(6))),
+        t3_17: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 6, 1, Some(LoudsNodeNum::new(4))),
+        t3_18: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 6, 2, Some(LoudsNodeNum::new(1))),
+        t3_19: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 6, 3, None),
+        t3_20: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 7, 0, Some(LoudsNodeNum::new(7))),
+        t3_21: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 7, 1, Some(LoudsNodeNum::new(4))),
+        t3_22: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 7, 2, Some(LoudsNodeNum::new(1))),
+        t3_23: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 7, 3, None),
+        t3_24: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 8, 0, Some(LoudsNodeNum::new(8))),
+        t3_25: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 8, 1, Some(LoudsNodeNum::new(4))),
+        t3_26: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 8, 2, Some(LoudsNodeNum::new(1))),
+        t3_27: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 8, 3, None),
+        t3_28: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 9, 0, Some(LoudsNodeNum::new(9))),
+        t3_29: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 9, 1, Some(LoudsNodeNum::new(7))),
+        t3_30: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 9, 2, Some(LoudsNodeNum::new(4))),
+        t3_31: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 9, 3, Some(LoudsNodeNum::new(1))),
+        t3_32: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 9, 4, None),
+        t3_33: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 10, 0, Some(LoudsNodeNum::new(10))),
+        t3_34: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 10, 1, Some(LoudsNodeNum::new(8))),
+        t3_35: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 10, 2, Some(LoudsNodeNum::new(4))),
+        t3_36: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 10, 3, Some(LoudsNodeNum::new(1))),
+        t3_37: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 10, 4, None),
+        t3_38: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 11, 0, Some(LoudsNodeNum::new(11))),
+        t3_39: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 11, 1, Some(LoudsNodeNum::new(8))),
+        t3_40: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 11, 2, Some(LoudsNodeNum::new(4))),
+        t3_41: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 11, 3, Some(LoudsNodeNum::new(1))),
+        t3_42: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 11, 4, None),
+    }
+}
+
+#[cfg(test)]
+mod level_ancestor_failure_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_node_not_found_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, node_num) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let _ = louds.level_ancestor(LoudsNodeNum::new(node_num), 0);
+            }
+        )*
+        }
+    }
+
+    parameterized_node_not_found_tests! {
+        t1_1: ("10_0", 0),
+        t1_2: ("10_0", 2),
+
+        t2_1: ("10_10_0", 0),
+        t2_2: ("10_10_0", 3),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 0),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 12),
+    }
+}
+
+#[cfg(test)]
+mod path_from_root_success_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, node_num, expected_path): (&str, u64, Vec<usize>) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let path = louds.path_from_root(LoudsNodeNum::new(node_num));
+                assert_eq!(path, expected_path);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", 1, vec!()),
+
+        t2_1: ("10_10_0", 1, vec!()),
+        t2_2: ("10_10_0", 2, vec!(0)),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, vec!()),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, vec!(0)),
+        t3_3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, vec!(1)),
+        t3_4: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, vec!(2)),
+        t3_5: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 5, vec!(0, 0)),
+        t3_6: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 6, vec!(2, 0)),
+        t3_7: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 7, vec!(2, 1)),
+        t3_8: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 8, vec!(2, 2)),
+        t3_9: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 9, vec!(2, 1, 0)),
+        t3_10: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 10, vec!(2, 2, 0)),
+        t3_11: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 11, vec!(2, 2, 1)),
+    }
+}
+
+#[cfg(test)]
+mod path_from_root_failure_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_node_not_found_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, node_num) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let _ = louds.path_from_root(LoudsNodeNum::new(node_num));
+            }
+        )*
+        }
+    }
+
+    parameterized_node_not_found_tests! {
+        t1_1: ("10_0", 0),
+        t1_2: ("10_0", 2),
+
+        t2_1: ("10_10_0", 0),
+        t2_2: ("10_10_0", 3),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 0),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 12),
+    }
+}
+
+#[cfg(test)]
+mod node_at_path_success_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, path, expected_node_num): (&str, &[usize], Option<LoudsNodeNum>) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let node_num = louds.node_at_path(path);
+                assert_eq!(node_num, expected_node_num);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", &[], Some(LoudsNodeNum::new(1))),
+        t1_2: ("10_0", &[0], None),
+
+        t2_1: ("10_10_0", &[], Some(LoudsNodeNum::new(1))),
+        t2_2: ("10_10_0", &[1], None),
+        t2_3: ("10_10_0", &[0], Some(LoudsNodeNum::new(2))),
+        t2_4: ("10_10_0", &[0, 0], None),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", &[], Some(LoudsNodeNum::new(1))),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", &[3], None),
+        t3_3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", &[0], Some(LoudsNodeNum::new(2))),
+        t3_4: ("10_1110_10_0_1110_0_0_10_110_0_0_0", &[0, 1], None),
+        t3_5: ("10_1110_10_0_1110_0_0_10_110_0_0_0", &[1], Some(LoudsNodeNum::new(3))),
+        t3_6: ("10_1110_10_0_1110_0_0_10_110_0_0_0", &[1, 0], None),
+        t3_7: ("10_1110_10_0_1110_0_0_10_110_0_0_0", &[2], Some(LoudsNodeNum::new(4))),
+        t3_8: ("10_1110_10_0_1110_0_0_10_110_0_0_0", &[2, 3], None),
+        t3_9: ("10_1110_10_0_1110_0_0_10_110_0_0_0", &[0, 0], Some(LoudsNodeNum::new(5))),
+        t3_10: ("10_1110_10_0_1110_0_0_10_110_0_0_0", &[0, 0, 0], None),
+        t3_11: ("10_1110_10_0_1110_0_0_10_110_0_0_0", &[2, 0], Some(LoudsNodeNum::new(6))),
+        t3_12: ("10_1110_10_0_1110_0_0_10_110_0_0_0", &[2, 0, 0], None),
+        t3_13: ("10_1110_10_0_1110_0_0_10_110_0_0_0", &[2, 1], Some(LoudsNodeNum::new(7))),
+        t3_14: ("10_1110_10_0_1110_0_0_10_110_0_0_0", &[2, 1, 1], None),
+        t3_15: ("10_1110_10_0_1110_0_0_10_110_0_0_0", &[2, 2], Some(LoudsNodeNum::new(8))),
+        t3_16: ("10_1110_10_0_1110_0_0_10_110_0_0_0", &[2, 2, 2], None),
+        t3_17: ("10_1110_10_0_1110_0_0_10_110_0_0_0", &[2, 1, 0], Some(LoudsNodeNum::new(9))),
+        t3_18: ("10_1110_10_0_1110_0_0_10_110_0_0_0", &[2, 1, 0, 0], None),
+        t3_19: ("10_1110_10_0_1110_0_0_10_110_0_0_0", &[2, 2, 0], Some(LoudsNodeNum::new(10))),
+        t3_20: ("10_1110_10_0_1110_0_0_10_110_0_0_0", &[2, 2, 0, 0], None),
+        t3_21: ("10_1110_10_0_1110_0_0_10_110_0_0_0", &[2, 2, 1], Some(LoudsNodeNum::new(11))),
+        t3_22: ("10_1110_10_0_1110_0_0_10_110_0_0_0", &[2, 2, 1, 0], None),
+        t3_23: ("10_1110_10_0_1110_0_0_10_110_0_0_0", &[usize::MAX], None),
+        t3_24: ("10_1110_10_0_1110_0_0_10_110_0_0_0", &[2, 2, usize::MAX], None),
+    }
+}
+
+#[cfg(test)]
+mod lca_success_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, a, b, expected_lca) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let lca = louds.lca(LoudsNodeNum::new(a), LoudsNodeNum::new(b));
+                assert_eq!(lca, LoudsNodeNum::new(expected_lca));
+                assert_eq!(louds.lca(LoudsNodeNum::new(b), LoudsNodeNum::new(a)), lca);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", 1, 1, 1),
+
+        t2_1: ("10_10_0", 1, 1, 1),
+        t2_2: ("10_10_0", 1, 2, 1),
+        t2_3: ("10_10_0", 2, 2, 2),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, 1, 1),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, 2, 1),
+        t3_3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, 3, 1),
+        t3_4: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, 4, 1),
+        t3_5: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, 5, 1),
+        t3_6: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, 6, 1),
+        t3_7: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, 7, 1),
+        t3_8: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, 8, 1),
+        t3_9: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, 9, 1),
+        t3_10: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, 10, 1),
+        t3_11: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, 11, 1),
+        t3_12: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, 2, 2),
+        t3_13: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, 3, 1),
+        t3_14: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, 4, 1),
+        t3_15: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, 5, 2),
+        t3_16: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, 6, 1),
+        t3_17: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, 7, 1),
+        t3_18: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, 8, 1),
+        t3_19: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, 9, 1),
+        t3_20: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, 10, 1),
+        t3_21: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, 11, 1),
+        t3_22: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, 3, 3),
+        t3_23: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, 4, 1),
+        t3_24: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, 5, 1),
+        t3_25: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, 6, 1),
+        t3_26: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, 7, 1),
+        t3_27: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, 8, 1),
+        t3_28: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, 9, 1),
+        t3_29: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, 10, 1),
+        t3_30: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, 11, 1),
+        t3_31: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, 4, 4),
+        t3_32: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, 5, 1),
+        t3_33: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, 6, 4),
+        t3_34: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, 7, 4),
+        t3_35: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, 8, 4),
+        t3_36: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, 9, 4),
+        t3_37: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, 10, 4),
+        t3_38: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, 11, 4),
+        t3_39: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 5, 5, 5),
+        t3_40: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 5, 6, 1),
+        t3_41: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 5, 7, 1),
+        t3_42: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 5, 8, 1),
+        t3_43: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 5, 9, 1),
+        t3_44: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 5, 10, 1),
+        t3_45: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 5, 11, 1),
+        t3_46: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 6, 6, 6),
+        t3_47: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 6, 7, 4),
+        t3_48: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 6, 8, 4),
+        t3_49: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 6, 9, 4),
+        t3_50: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 6, 10, 4),
+        t3_51: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 6, 11, 4),
+        t3_52: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 7, 7, 7),
+        t3_53: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 7, 8, 4),
+        t3_54: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 7, 9, 7),
+        t3_55: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 7, 10, 4),
+        t3_56: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 7, 11, 4),
+        t3_57: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 8, 8, 8),
+        t3_58: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 8, 9, 4),
+        t3_59: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 8, 10, 8),
+        t3_60: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 8, 11, 8),
+        t3_61: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 9, 9, 9),
+        t3_62: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 9, 10, 4),
+        t3_63: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 9, 11, 4),
+        t3_64: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 10, 10, 10),
+        t3_65: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 10, 11, 8),
+        t3_66: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 11, 11, 11),
+    }
+}
+
+#[cfg(test)]
+mod lca_failure_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_node_not_found_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, a, b) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let _ = louds.lca(LoudsNodeNum::new(a), LoudsNodeNum::new(b));
+            }
+        )*
+        }
+    }
+
+    parameterized_node_not_found_tests! {
+        t1_1: ("10_0", 0, 1),
+        t1_2: ("10_0", 1, 2),
+
+        t2_1: ("10_10_0", 3, 1),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 0, 5),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 5, 12),
+    }
+}
+
+#[cfg(test)]
+mod leaf_rank_select_success_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, expected_leaf_ranks, expected_leaves): (&str, Vec<u64>, Vec<u64>) = $value;
+                let louds = LoudsBuilder::from_bit_string(BitString::new(in_s)).build();
+
+                for (i, expected_leaf_rank) in expected_leaf_ranks.iter().enumerate() {
+                    assert_eq!(louds.leaf_rank(LoudsNodeNum::new(i as u64 + 1)), *expected_leaf_rank);
+                }
+                for (k, expected_leaf) in expected_leaves.iter().enumerate() {
+                    assert_eq!(louds.leaf_select(k as u64), Some(LoudsNodeNum::new(*expected_leaf)));
+                }
+                assert_eq!(louds.leaf_select(expected_leaves.len() as u64), None);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: ("10_0", vec!(0), vec!(1)),
+        t2: ("10_10_0", vec!(0, 0), vec!(2)),
+        t3: ("10_1110_10_0_1110_0_0_10_110_0_0_0",
+             vec!(0, 0, 0, 1, 1, 2, 3, 3, 3, 4, 5),
+             vec!(3, 5, 6, 9, 10, 11)),
+        t4: ("10_1111_0_0_0_0_0", vec!(0, 0, 1, 2, 3), vec!(2, 3, 4, 5)),
+    }
+}
+
+#[cfg(test)]
+mod leaf_rank_failure_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_node_not_found_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, node_num) = $value;
+                let louds = LoudsBuilder::from_bit_string(BitString::new(in_s)).build();
+                let _ = louds.leaf_rank(LoudsNodeNum::new(node_num));
+            }
+        )*
+        }
+    }
+
+    parameterized_node_not_found_tests! {
+        t1_1: ("10_0", 0),
+        t1_2: ("10_0", 2),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 0),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 12),
+    }
+}
+
+#[cfg(test)]
+mod level_range_success_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, expected_ranges): (&str, Vec<(u64, u64)>) = $value;
+                let louds = LoudsBuilder::from_bit_string(BitString::new(in_s)).build();
+
+                assert_eq!(louds.height() + 1, expected_ranges.len() as u64);
+                for (d, (start, end)) in expected_ranges.iter().enumerate() {
+                    let d = d as u64;
+                    assert_eq!(louds.level_range(d), LoudsNodeNum::new(*start)..LoudsNodeNum::new(*end));
+                    assert_eq!(louds.level_width(d), end - start);
+                    for node_num in *start..*end {
+                        assert_eq!(louds.level_of(LoudsNodeNum::new(node_num)), d);
+                    }
+                }
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: ("10_0", vec!((1, 2))),
+        t2: ("10_10_0", vec!((1, 2), (2, 3))),
+        t3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!((1, 2), (2, 5), (5, 9), (9, 12))),
+        t4: ("10_11110_0_0_0_0", vec!((1, 2), (2, 6))),
+    }
+}
+
+#[cfg(test)]
+mod level_range_failure_tests {
+    use crate::{BitString, LoudsBuilder};
+
+    macro_rules! parameterized_depth_out_of_range_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, d) = $value;
+                let louds = LoudsBuilder::from_bit_string(BitString::new(in_s)).build();
+                let _ = louds.level_range(d);
+            }
+        )*
+        }
+    }
+
+    parameterized_depth_out_of_range_tests! {
+        t1: ("10_0", 1),
+        t2: ("10_10_0", 2),
+        t3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4),
+    }
+}
+
+#[cfg(test)]
+mod level_width_failure_tests {
+    use crate::{BitString, LoudsBuilder};
+
+    macro_rules! parameterized_depth_out_of_range_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, d) = $value;
+                let louds = LoudsBuilder::from_bit_string(BitString::new(in_s)).build();
+                let _ = louds.level_width(d);
+            }
+        )*
+        }
+    }
+
+    parameterized_depth_out_of_range_tests! {
+        t1: ("10_0", 1),
+        t3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4),
+    }
+}
+
+#[cfg(test)]
+mod next_sibling_success_tests {
+    use crate::{BitString, LoudsBuilder, LoudsIndex};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, index, expected_sibling) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let sibling = louds.next_sibling(LoudsIndex::new(index));
+                assert_eq!(sibling, expected_sibling.map(LoudsIndex::new));
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", 0, None),
+
+        t2_1: ("10_10_0", 0, None),
+        t2_2: ("10_10_0", 2, None),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 0, None),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, Some(3)),
+        t3_3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, Some(4)),
+        t3_4: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, None),
+        t3_5: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 6, None),
+        t3_6: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 9, Some(10)),
+        t3_7: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 10, Some(11)),
+        t3_8: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 11, None),
+        t3_9: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 15, None),
+        t3_10: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 17, Some(18)),
+        t3_11: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 18, None),
+    }
+}
+
+#[cfg(test)]
+mod next_sibling_failure_tests {
+    use crate::{BitString, LoudsBuilder, LoudsIndex};
+
+    macro_rules! parameterized_index_not_point_to_node_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, index) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let _ = louds.next_sibling(LoudsIndex::new(index));
+            }
+        )*
+        }
+    }
+
+    parameterized_index_not_point_to_node_tests! {
+        t1_1: ("10_0", 1),
+        t1_2: ("10_0", 3),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 5),
+        t3_3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 22),
+        t3_4: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 23),
+    }
+}
+
+#[cfg(test)]
+mod prev_sibling_success_tests {
+    use crate::{BitString, LoudsBuilder, LoudsIndex};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, index, expected_sibling) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let sibling = louds.prev_sibling(LoudsIndex::new(index));
+                assert_eq!(sibling, expected_sibling.map(LoudsIndex::new));
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", 0, None),
+
+        t2_1: ("10_10_0", 0, None),
+        t2_2: ("10_10_0", 2, None),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 0, None),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, None),
+        t3_3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, Some(2)),
+        t3_4: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, Some(3)),
+        t3_5: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 6, None),
+        t3_6: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 9, None),
+        t3_7: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 10, Some(9)),
+        t3_8: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 11, Some(10)),
+        t3_9: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 15, None),
+        t3_10: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 17, None),
+        t3_11: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 18, Some(17)),
+    }
+}
+
+#[cfg(test)]
+mod prev_sibling_failure_tests {
+    use crate::{BitString, LoudsBuilder, LoudsIndex};
+
+    macro_rules! parameterized_index_not_point_to_node_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, index) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                let _ = louds.prev_sibling(LoudsIndex::new(index));
+            }
+        )*
+        }
+    }
+
+    parameterized_index_not_point_to_node_tests! {
+        t1_1: ("10_0", 1),
+        t1_2: ("10_0", 3),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 5),
+        t3_3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 22),
+        t3_4: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 23),
+    }
+}
+
+#[cfg(test)]
+mod to_dot_success_tests {
+    use crate::{BitString, LoudsBuilder};
+
+    #[test]
+    fn test() {
+        let bs = BitString::new("10_1110_10_0_1110_0_0_10_110_0_0_0");
+        let louds = LoudsBuilder::from_bit_string(bs).build();
+        assert_eq!(
+            louds.to_dot(),
+            r#"digraph louds {
+    1 [label="1\n<0>"];
+    2 [label="2\n<2>"];
+    3 [label="3\n<3>"];
+    4 [label="4\n<4>"];
+    5 [label="5\n<6>"];
+    6 [label="6\n<9>"];
+    7 [label="7\n<10>"];
+    8 [label="8\n<11>"];
+    9 [label="9\n<15>"];
+    10 [label="10\n<17>"];
+    11 [label="11\n<18>"];
+    1 -> 2;
+    1 -> 3;
+    1 -> 4;
+    2 -> 5;
+    4 -> 6;
+    4 -> 7;
+    4 -> 8;
+    7 -> 9;
+    8 -> 10;
+    8 -> 11;
+}
+"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod to_ascii_tree_success_tests {
+    use crate::{BitString, LoudsBuilder};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, expected_tree) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                assert_eq!(louds.to_ascii_tree(), expected_tree);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: ("10_0", "(1) <0>\n"),
+        t2: ("10_10_0", "(1) <0>\n`-- (2) <2>\n"),
+        t3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", "\
+(1) <0>
+|-- (2) <2>
+|   `-- (5) <6>
+|-- (3) <3>
+`-- (4) <4>
+    |-- (6) <9>
+    |-- (7) <10>
+    |   `-- (9) <15>
+    `-- (8) <11>
+        |-- (10) <17>
+        `-- (11) <18>
+"),
+    }
+}
+
+#[cfg(test)]
+mod fmt_success_tests {
+    use crate::{BitString, LoudsBuilder};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, expected_display, expected_debug) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+                assert_eq!(format!("{}", louds), expected_display);
+                assert_eq!(format!("{:?}", louds), expected_debug);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: ("10_0", "10_0 (nodes: 1)", "Louds { nodes: 1, lbs: \"10_0\" }"),
+        t3: ("10_1110_10_0_1110_0_0_10_110_0_0_0",
+             "10_1110_10_0_1110_0_0_10_110_0_0_0 (nodes: 11)",
+             "Louds { nodes: 11, lbs: \"10_1110_10_0_1110_0_0_10_110_0_0_0\" }"),
+        t_truncated: ("10_111111111111111111111111111111111110_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0",
+             "10_111111111111111111111111111111111110_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_... (nodes: 36)",
+             "Louds { nodes: 36, lbs: \"10_111111111111111111111111111111111110_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_0_...\" }"),
+    }
+}
@@ -0,0 +1,26 @@
+use super::{Louds, LoudsAncestors, LoudsNodeNum};
+
+impl<'a> LoudsAncestors<'a> {
+    /// Constructor. Iteration starts from the parent of `node_num`.
+    pub(super) fn new(louds: &'a Louds, node_num: u64) -> Self {
+        Self { louds, node_num }
+    }
+}
+
+impl<'a> Iterator for LoudsAncestors<'a> {
+    type Item = LoudsNodeNum;
+
+    fn next(&mut self) -> Option<LoudsNodeNum> {
+        // node#1 (root) has no parent. 0 marks the end of iteration.
+        if self.node_num <= 1 {
+            self.node_num = 0;
+            return None;
+        }
+        let index = self
+            .louds
+            .node_num_to_index(LoudsNodeNum::new(self.node_num));
+        let parent = self.louds.child_to_parent(index);
+        self.node_num = parent.value();
+        Some(parent)
+    }
+}
@@ -1,4 +1,5 @@
-use super::{Louds, LoudsBuilder};
+use super::{Louds, LoudsBuilder, LoudsNodeNum};
+use crate::internal_data_structure::raw_bit_vector::RawBitVector;
 use crate::succinct_bit_vector::SuccinctBitVectorBuilder;
 use crate::BitString;
 
@@ -23,37 +24,288 @@ impl super::LoudsBuilder {
         LoudsBuilder { bv_builder }
     }
 
+    /// Prepares for building [Louds](struct.Louds.html) from a parent array: `parents[id]` is the parent id of node `id`, or `None` for the root.
+    ///
+    /// Children are ordered by their ids. Returns the builder and the mapping from ids to NodeNums (`node_nums[id]`).
+    ///
+    /// # Examples
+    /// ```
+    /// use succinct_rs::{LoudsBuilder, LoudsNodeNum};
+    ///
+    /// //     2
+    /// //     |
+    /// //     3
+    /// //   / | \
+    /// //  0  1  4
+    /// let (builder, node_nums) = LoudsBuilder::from_parent_array(&[Some(3), Some(3), None, Some(2), Some(3)]);
+    /// let louds = builder.build();
+    /// assert_eq!(format!("{}", louds), "10_10_1110_0_0_0 (nodes: 5)");
+    /// assert_eq!(node_nums[2], LoudsNodeNum::new(1));
+    /// assert_eq!(node_nums[4], LoudsNodeNum::new(5));
+    /// ```
+    ///
+    /// # Panics
+    /// If `parents` does not represent a tree:
+    ///
+    /// - `parents` is empty, or has no or several `None`.
+    /// - A parent id is out of range.
+    /// - A node is not reachable from the root (i.e. parents make a cycle).
+    pub fn from_parent_array(parents: &[Option<usize>]) -> (Self, Vec<LoudsNodeNum>) {
+        let mut root = None;
+        let mut children = vec![vec![]; parents.len()];
+        for (id, parent) in parents.iter().enumerate() {
+            match parent {
+                None => {
+                    if let Some(root) = root {
+                        panic!("Both node {} and node {} are roots", root, id);
+                    }
+                    root = Some(id);
+                }
+                Some(parent) => {
+                    assert!(
+                        *parent < parents.len(),
+                        "Parent id {} of node {} is out of range",
+                        parent,
+                        id
+                    );
+                    children[*parent].push(id);
+                }
+            }
+        }
+        let root = root.expect("`parents` must have a root (`None`)");
+
+        LoudsBuilder::from_children_lists(&children, root)
+    }
+
+    /// Prepares for building [Louds](struct.Louds.html) from adjacency lists: `children[id]` lists the child ids of node `id` from left to right.
+    ///
+    /// Returns the builder and the mapping from ids to NodeNums (`node_nums[id]`).
+    ///
+    /// # Examples
+    /// ```
+    /// use succinct_rs::{LoudsBuilder, LoudsNodeNum};
+    ///
+    /// let (builder, node_nums) = LoudsBuilder::from_children_lists(&[vec![], vec![2, 0], vec![]], 1);
+    /// let louds = builder.build();
+    /// assert_eq!(format!("{}", louds), "10_110_0_0 (nodes: 3)");
+    /// assert_eq!(node_nums, vec!(LoudsNodeNum::new(3), LoudsNodeNum::new(1), LoudsNodeNum::new(2)));
+    /// ```
+    ///
+    /// # Panics
+    /// If `children` does not represent a tree rooted at `root`:
+    ///
+    /// - `root` or a child id is out of range.
+    /// - A node is listed as a child more than once, or the root is listed as a child.
+    /// - A node is not reachable from `root`.
+    pub fn from_children_lists<C: AsRef<[usize]>>(
+        children: &[C],
+        root: usize,
+    ) -> (Self, Vec<LoudsNodeNum>) {
+        let n = children.len();
+        assert!(root < n, "Root id {} is out of range", root);
+
+        let mut lbs = LoudsBuilder::virtual_root();
+        let mut node_nums: Vec<Option<u64>> = vec![None; n];
+        node_nums[root] = Some(1);
+
+        // Queue of BFS, which never shrinks: `order[k - 1]` is node#k's id.
+        let mut order = vec![root];
+        let mut i = 0;
+        while i < order.len() {
+            let cs = children[order[i]].as_ref();
+            for &c in cs {
+                assert!(c < n, "Child id {} of node {} is out of range", c, order[i]);
+                assert!(
+                    node_nums[c].is_none(),
+                    "Node {} is reached more than once",
+                    c
+                );
+                order.push(c);
+                node_nums[c] = Some(order.len() as u64);
+            }
+            LoudsBuilder::add_node(&mut lbs, cs.len());
+            i += 1;
+        }
+
+        let node_nums = node_nums
+            .iter()
+            .enumerate()
+            .map(|(id, node_num)| {
+                LoudsNodeNum::new(node_num.unwrap_or_else(|| {
+                    panic!("Node {} is not reachable from the root {}", id, root)
+                }))
+            })
+            .collect();
+        (LoudsBuilder::from_lbs(lbs), node_nums)
+    }
+
+    /// Prepares for building [Louds](struct.Louds.html) from any tree: `children_fn(node)` returns `node`'s children from left to right.
+    ///
+    /// Returns the builder and each node paired with its NodeNum, in NodeNum order.
+    ///
+    /// _WARNING_: `children_fn` must describe a tree. Shared children are encoded as many times as they are reached, and cycles never end.
+    ///
+    /// # Examples
+    /// ```
+    /// use succinct_rs::{LoudsBuilder, LoudsNodeNum};
+    ///
+    /// struct Node {
+    ///     name: &'static str,
+    ///     children: Vec<Node>,
+    /// }
+    ///
+    /// let root = Node {
+    ///     name: "a",
+    ///     children: vec![
+    ///         Node { name: "b", children: vec![Node { name: "d", children: vec![] }] },
+    ///         Node { name: "c", children: vec![] },
+    ///     ],
+    /// };
+    /// let (builder, nodes) = LoudsBuilder::from_tree(&root, |node| node.children.iter());
+    /// let louds = builder.build();
+    /// assert_eq!(format!("{}", louds), "10_110_10_0_0 (nodes: 4)");
+    /// assert_eq!(
+    ///     nodes.iter().map(|(node, node_num)| (node.name, node_num.value())).collect::<Vec<_>>(),
+    ///     vec!(("a", 1), ("b", 2), ("c", 3), ("d", 4))
+    /// );
+    /// ```
+    pub fn from_tree<T, F, I>(root: T, mut children_fn: F) -> (Self, Vec<(T, LoudsNodeNum)>)
+    where
+        F: FnMut(&T) -> I,
+        I: IntoIterator<Item = T>,
+    {
+        let mut lbs = LoudsBuilder::virtual_root();
+
+        // Queue of BFS, which never shrinks: `order[k - 1]` is node#k.
+        let mut order = vec![root];
+        let mut i = 0;
+        while i < order.len() {
+            let before = order.len();
+            order.extend(children_fn(&order[i]));
+            LoudsBuilder::add_node(&mut lbs, order.len() - before);
+            i += 1;
+        }
+
+        let nodes = order
+            .into_iter()
+            .enumerate()
+            .map(|(k, node)| (node, LoudsNodeNum::new(k as u64 + 1)))
+            .collect();
+        (LoudsBuilder::from_lbs(lbs), nodes)
+    }
+
+    /// Prepares for building [Louds](struct.Louds.html) from the degrees (numbers of children) of nodes in level order: node#1 (root), node#2, ...
+    ///
+    /// The unary degrees are emitted directly into LBS, so it takes _O(N)_ time and never materializes a [BitString](../bit_string/struct.BitString.html).
+    ///
+    /// # Examples
+    /// ```
+    /// use succinct_rs::{LoudsBuilder, LoudsNodeNum};
+    ///
+    /// let louds = LoudsBuilder::from_degrees(vec![3, 1, 0, 3, 0, 0, 1, 2, 0, 0, 0]).build();
+    /// assert_eq!(format!("{}", louds), "10_1110_10_0_1110_0_0_10_110_0_0_0 (nodes: 11)");
+    /// assert_eq!(louds.degree(LoudsNodeNum::new(8)), 2);
+    /// ```
+    ///
+    /// # Panics
+    /// If `degrees` does not describe exactly one tree:
+    ///
+    /// - `degrees` is empty.
+    /// - `degrees` ends while some children are not given their degrees.
+    /// - `degrees` continues after every node got its degree.
+    pub fn from_degrees<I: IntoIterator<Item = usize>>(degrees: I) -> Self {
+        let mut lbs = LoudsBuilder::virtual_root();
+
+        // The number of nodes given their degrees, and the number of nodes known to exist (root + children so far).
+        let (mut nodes_visited, mut nodes_found) = (0u64, 1u64);
+        for degree in degrees {
+            assert!(
+                nodes_visited < nodes_found,
+                "Degrees continue after all the {} nodes of the tree",
+                nodes_found
+            );
+            LoudsBuilder::add_node(&mut lbs, degree);
+            nodes_visited += 1;
+            nodes_found += degree as u64;
+        }
+        assert!(nodes_visited > 0, "Degrees must not be empty");
+        assert_eq!(
+            nodes_visited, nodes_found,
+            "Degrees end after {} nodes while the tree has {} nodes",
+            nodes_visited, nodes_found
+        );
+
+        LoudsBuilder::from_lbs(lbs)
+    }
+
     /// Build [Louds](struct.Louds.html).
     ///
     /// It internally calls [SuccinctBitVectorBuilder::build()](../succinct_bit_vector/struct.SuccinctBitVectorBuilder.html#method.build) and takes _O(log N)_ where _N_ is the length of LBS.
+    /// Then it computes the level boundaries used by [Louds::depth()](struct.Louds.html#method.depth) in _O(H log N)_ where _H_ is the height of the tree.
+    /// It also samples '1's and '0's of LBS for constant-time select, and counts "00" in LBS per block for [Louds::leaf_rank()](struct.Louds.html#method.leaf_rank), both in _O(N)_.
     pub fn build(&self) -> Louds {
         let bv = self.bv_builder.build();
-        Louds { lbs: bv }
+        Louds::from_lbs(bv)
+    }
+
+    /// Wraps a valid LBS.
+    fn from_lbs(lbs: RawBitVector) -> Self {
+        LoudsBuilder {
+            bv_builder: SuccinctBitVectorBuilder::from_raw_bit_vector(lbs),
+        }
+    }
+
+    /// Starts LBS with the virtual root's "10".
+    fn virtual_root() -> RawBitVector {
+        let mut lbs = RawBitVector::from_length(2);
+        lbs.set_bit(0);
+        lbs
+    }
+
+    /// Appends a node's unary degree: `degree` '1's and a '0'.
+    pub(super) fn add_node(lbs: &mut RawBitVector, degree: usize) {
+        for _ in 0..degree {
+            lbs.push(true);
+        }
+        lbs.push(false);
     }
 
     /// Checks if `bs` satisfy the LBS's necessary and sufficient condition:
     fn validate_lbs(bs: &BitString) {
-        let s = bs.str();
-
-        assert!(s.starts_with("10"));
+        if let Err(msg) = LoudsBuilder::check_lbs(bs.str().chars().map(|ch| ch == '1')) {
+            panic!("{}", msg);
+        }
+    }
 
+    /// Non-panicking body of `validate_lbs()`, also used to validate deserialized LBS.
+    pub(super) fn check_lbs<I: IntoIterator<Item = bool>>(bits: I) -> Result<(), String> {
         let (mut cnt0, mut cnt1) = (0u64, 0u64);
-        for (i, ch) in s.chars().enumerate() {
-            match ch {
-                '0' => cnt0 += 1,
-                '1' => cnt1 += 1,
-                c => panic!("LBS contains invalid character '{}'", c),
+        for (i, bit) in bits.into_iter().enumerate() {
+            if (i == 0 && !bit) || (i == 1 && bit) {
+                return Err("LBS must start from \"10\"".to_string());
+            }
+            if bit {
+                cnt1 += 1;
+            } else {
+                cnt0 += 1;
+            }
+            if cnt0 > cnt1 + 1 {
+                return Err(format!(
+                    "At index {}, the number of '0' ({}) == (the number of '1' ({})) + 2.",
+                    i, cnt0, cnt1,
+                ));
             }
-            assert!(
-                cnt0 <= cnt1 + 1,
-                "At index {}, the number of '0' ({}) == (the number of '1' ({})) + 2.",
-                i,
-                cnt0,
-                cnt1,
-            );
         }
-
-        assert_eq!(cnt0, cnt1 + 1);
+        if cnt0 + cnt1 < 2 {
+            return Err("LBS must start from \"10\"".to_string());
+        }
+        if cnt0 != cnt1 + 1 {
+            return Err(format!(
+                "LBS has {} '0's and {} '1's while the number of '0' must be the number of '1' + 1.",
+                cnt0, cnt1,
+            ));
+        }
+        Ok(())
     }
 }
 
@@ -116,3 +368,189 @@ mod validate_lbs_failure_tests {
         t13: "10_1110_10_0_1110_0_0_10_110_0_0_1",
     }
 }
+
+#[cfg(test)]
+mod from_parent_array_success_tests {
+    use crate::{LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (parents, expected_louds, expected_node_nums): (Vec<Option<usize>>, &str, Vec<u64>) = $value;
+                let (builder, node_nums) = LoudsBuilder::from_parent_array(&parents);
+                let louds = builder.build();
+                assert_eq!(format!("{}", louds), expected_louds);
+                assert_eq!(node_nums, expected_node_nums.into_iter().map(LoudsNodeNum::new).collect::<Vec<LoudsNodeNum>>());
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: (vec!(None), "10_0 (nodes: 1)", vec!(1)),
+        t2: (vec!(None, Some(0)), "10_10_0 (nodes: 2)", vec!(1, 2)),
+        t3: (
+            vec!(Some(3), Some(3), None, Some(2), Some(3), Some(0), Some(1)),
+            "10_10_1110_10_10_0_0_0 (nodes: 7)",
+            vec!(3, 4, 1, 2, 5, 6, 7)
+        ),
+        t4: (
+            vec!(None, Some(0), Some(0), Some(0), Some(1), Some(3), Some(3), Some(3), Some(5), Some(7), Some(7)),
+            "10_1110_10_0_1110_0_10_0_110_0_0_0 (nodes: 11)",
+            vec!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11)
+        ),
+    }
+}
+
+#[cfg(test)]
+mod from_parent_array_failure_tests {
+    use crate::LoudsBuilder;
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let parents: Vec<Option<usize>> = $value;
+                let _ = LoudsBuilder::from_parent_array(&parents);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: vec!(),
+        t2: vec!(Some(0)),
+        t3: vec!(None, None),
+        t4: vec!(None, Some(2)),
+        t5: vec!(None, Some(2), Some(1)),
+        t6: vec!(None, Some(1)),
+    }
+}
+
+#[cfg(test)]
+mod from_children_lists_success_tests {
+    use crate::{LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (children, root, expected_louds, expected_node_nums): (Vec<Vec<usize>>, usize, &str, Vec<u64>) = $value;
+                let (builder, node_nums) = LoudsBuilder::from_children_lists(&children, root);
+                let louds = builder.build();
+                assert_eq!(format!("{}", louds), expected_louds);
+                assert_eq!(node_nums, expected_node_nums.into_iter().map(LoudsNodeNum::new).collect::<Vec<LoudsNodeNum>>());
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: (vec!(vec!()), 0, "10_0 (nodes: 1)", vec!(1)),
+        t2: (vec!(vec!(), vec!(0)), 1, "10_10_0 (nodes: 2)", vec!(2, 1)),
+        t3: (
+            vec!(vec!(5), vec!(6), vec!(3), vec!(0, 1, 4), vec!(), vec!(), vec!()),
+            2,
+            "10_10_1110_10_10_0_0_0 (nodes: 7)",
+            vec!(3, 4, 1, 2, 5, 6, 7)
+        ),
+        t4: (
+            vec!(vec!(), vec!(0, 2), vec!()),
+            1,
+            "10_110_0_0 (nodes: 3)",
+            vec!(2, 1, 3)
+        ),
+        t5: (
+            vec!(vec!(), vec!(2, 0), vec!()),
+            1,
+            "10_110_0_0 (nodes: 3)",
+            vec!(3, 1, 2)
+        ),
+    }
+}
+
+#[cfg(test)]
+mod from_children_lists_failure_tests {
+    use crate::LoudsBuilder;
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (children, root): (Vec<Vec<usize>>, usize) = $value;
+                let _ = LoudsBuilder::from_children_lists(&children, root);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: (vec!(), 0),
+        t2: (vec!(vec!()), 1),
+        t3: (vec!(vec!(1), vec!()), 1),
+        t4: (vec!(vec!(2)), 0),
+        t5: (vec!(vec!(1, 1), vec!()), 0),
+        t6: (vec!(vec!(1), vec!(0)), 0),
+        t7: (vec!(vec!(1, 2), vec!(2), vec!()), 0),
+    }
+}
+
+#[cfg(test)]
+mod from_degrees_success_tests {
+    use crate::LoudsBuilder;
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (degrees, expected_louds): (Vec<usize>, &str) = $value;
+                let louds = LoudsBuilder::from_degrees(degrees).build();
+                assert_eq!(format!("{}", louds), expected_louds);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: (vec!(0), "10_0 (nodes: 1)"),
+        t2: (vec!(1, 0), "10_10_0 (nodes: 2)"),
+        t3: (vec!(3, 1, 0, 3, 0, 0, 1, 2, 0, 0, 0), "10_1110_10_0_1110_0_0_10_110_0_0_0 (nodes: 11)"),
+        t4: (vec!(10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0), "10_11111111110_0_0_0_0_0_0_0_0_0_0 (nodes: 11)"),
+    }
+}
+
+#[cfg(test)]
+mod from_degrees_failure_tests {
+    use crate::LoudsBuilder;
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let degrees: Vec<usize> = $value;
+                let _ = LoudsBuilder::from_degrees(degrees);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: vec!(),
+        t2: vec!(1),
+        t3: vec!(0, 0),
+        t4: vec!(2, 0),
+        t5: vec!(1, 0, 0),
+        t6: vec!(3, 1, 0, 3, 0, 0, 1, 2, 0, 0),
+        t7: vec!(3, 1, 0, 3, 0, 0, 1, 2, 0, 0, 0, 0),
+    }
+}
@@ -0,0 +1,24 @@
+use super::{LoudsChildren, LoudsIndex};
+use crate::SuccinctBitVector;
+
+impl<'a> LoudsChildren<'a> {
+    /// Constructor. `start` is the index of `lbs` right after the parent's '0'.
+    pub(super) fn new(lbs: &'a SuccinctBitVector, start: u64) -> Self {
+        Self { lbs, next: start }
+    }
+}
+
+impl<'a> Iterator for LoudsChildren<'a> {
+    type Item = LoudsIndex;
+
+    fn next(&mut self) -> Option<LoudsIndex> {
+        // The parent's unary degree always ends with '0', so `self.next` never runs off LBS.
+        if self.lbs.access(self.next) {
+            let index = LoudsIndex::new(self.next);
+            self.next += 1;
+            Some(index)
+        } else {
+            None
+        }
+    }
+}
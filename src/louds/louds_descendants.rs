@@ -0,0 +1,38 @@
+use super::{Louds, LoudsDescendants, LoudsNodeNum};
+
+impl<'a> LoudsDescendants<'a> {
+    /// Constructor. Iteration starts from the leftmost child of `node_num`.
+    pub(super) fn new(louds: &'a Louds, node_num: u64) -> Self {
+        Self {
+            louds,
+            level_start: node_num,
+            level_end: node_num + 1,
+            next: node_num + 1,
+        }
+    }
+}
+
+impl<'a> Iterator for LoudsDescendants<'a> {
+    type Item = LoudsNodeNum;
+
+    fn next(&mut self) -> Option<LoudsNodeNum> {
+        if self.next == self.level_end {
+            // An empty level has no descendants below.
+            if self.level_start == self.level_end {
+                return None;
+            }
+            let (start, end) = self
+                .louds
+                .next_level_node_nums(self.level_start, self.level_end);
+            self.level_start = start;
+            self.level_end = end;
+            self.next = start;
+            if start == end {
+                return None;
+            }
+        }
+        let node_num = LoudsNodeNum::new(self.next);
+        self.next += 1;
+        Some(node_num)
+    }
+}
@@ -0,0 +1,453 @@
+use super::{
+    LabeledLouds, LabeledLoudsEdit, Louds, LoudsBuilder, LoudsEdit, LoudsEdited, LoudsNodeNum,
+};
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+
+/// A node of the edited tree.
+enum EditedNode {
+    /// Node of the original tree.
+    Old(u64),
+    /// Leaf inserted by the given insertion number.
+    Inserted(usize),
+}
+
+impl Louds {
+    /// Starts accumulating edits on this tree. This tree itself is never modified.
+    ///
+    /// # Examples
+    /// ```
+    /// use succinct_rs::{BitString, LoudsBuilder, LoudsNodeNum};
+    ///
+    /// let louds = LoudsBuilder::from_bit_string(BitString::new("10_110_10_0_0")).build();
+    ///
+    /// let mut edit = louds.edit();
+    /// edit.remove_subtree(LoudsNodeNum::new(2));
+    /// let new_leaf = edit.insert_child(LoudsNodeNum::new(3), 0);
+    /// let edited = edit.apply();
+    ///
+    /// assert_eq!(format!("{}", edited.tree()), "10_10_10_0 (nodes: 3)");
+    /// assert_eq!(edited.new_node_num(LoudsNodeNum::new(3)), Some(LoudsNodeNum::new(2)));
+    /// assert_eq!(edited.new_node_num(LoudsNodeNum::new(4)), None);
+    /// assert_eq!(edited.inserted_node_num(new_leaf), Some(LoudsNodeNum::new(3)));
+    /// ```
+    pub fn edit(&self) -> LoudsEdit<'_> {
+        LoudsEdit {
+            louds: self,
+            inserts: vec![],
+            removed: HashSet::new(),
+        }
+    }
+}
+
+impl<'a> LoudsEdit<'a> {
+    /// Inserts a leaf as a child of `parent`, before its `position`-th (0-origin) original child (or as the last child when `position` is its degree).
+    ///
+    /// Leaves inserted at the same position keep the order of insertion. Returns the insertion number used by [LoudsEdited::inserted_node_num()](struct.LoudsEdited.html#method.inserted_node_num).
+    ///
+    /// # Panics
+    /// - `parent` does not exist in the original tree.
+    /// - _`position` > <u>the number of children of `parent`</u>_.
+    pub fn insert_child(&mut self, parent: impl Borrow<LoudsNodeNum>, position: u64) -> usize {
+        let parent = parent.borrow();
+        let degree = self.louds.degree(parent);
+        assert!(
+            position <= degree,
+            "position = {} must be <= {} (the number of children of NodeNum({}))",
+            position,
+            degree,
+            parent.value()
+        );
+
+        let insertion = self.inserts.len();
+        self.inserts.push((parent.value(), position, insertion));
+        insertion
+    }
+
+    /// Removes `node` and all its descendants, including leaves inserted under them.
+    ///
+    /// # Panics
+    /// - `node` does not exist in the original tree.
+    /// - `node` is the root.
+    pub fn remove_subtree(&mut self, node: impl Borrow<LoudsNodeNum>) {
+        let node = node.borrow();
+        assert!(!self.louds.is_root(node), "The root cannot be removed");
+        self.removed.insert(node.value());
+    }
+
+    /// Builds the edited tree.
+    ///
+    /// It visits the nodes of the edited tree once in level order and takes _O(N + I log I)_ time where _N_ is the number of original nodes and _I_ is the number of insertions.
+    pub fn apply(&self) -> LoudsEdited<Louds> {
+        let (louds, order) = self.plan();
+        LoudsEdited::new(louds, order, self.louds.nodes_cnt(), self.inserts.len())
+    }
+
+    /// Builds the edited tree and returns it with its nodes in level order.
+    fn plan(&self) -> (Louds, Vec<EditedNode>) {
+        // parent => [(position, insertion number)], sorted by position then insertion number.
+        let mut inserts: HashMap<u64, Vec<(u64, usize)>> = HashMap::new();
+        for &(parent, position, insertion) in &self.inserts {
+            inserts
+                .entry(parent)
+                .or_default()
+                .push((position, insertion));
+        }
+        for children in inserts.values_mut() {
+            children.sort();
+        }
+
+        let mut degrees = vec![];
+        let mut order = vec![EditedNode::Old(1)];
+        let mut i = 0;
+        while i < order.len() {
+            let before = order.len();
+            if let EditedNode::Old(node_num) = order[i] {
+                let (first, end) = self.louds.children_node_nums(node_num);
+                let no_inserts = vec![];
+                let mut inserted = inserts
+                    .get(&node_num)
+                    .unwrap_or(&no_inserts)
+                    .iter()
+                    .peekable();
+                for (position, child) in (first..end).enumerate() {
+                    while let Some(&&(_, insertion)) =
+                        inserted.peek().filter(|(p, _)| *p == position as u64)
+                    {
+                        order.push(EditedNode::Inserted(insertion));
+                        inserted.next();
+                    }
+                    if !self.removed.contains(&child) {
+                        order.push(EditedNode::Old(child));
+                    }
+                }
+                // Inserted as the last children.
+                for &(_, insertion) in inserted {
+                    order.push(EditedNode::Inserted(insertion));
+                }
+            }
+            degrees.push(order.len() - before);
+            i += 1;
+        }
+
+        (LoudsBuilder::from_degrees(degrees).build(), order)
+    }
+}
+
+impl<T: Clone> LabeledLouds<T> {
+    /// Starts accumulating edits on this tree and its labels. This tree itself is never modified.
+    ///
+    /// # Examples
+    /// ```
+    /// use succinct_rs::{BitString, LabeledLouds, LoudsBuilder, LoudsNodeNum};
+    ///
+    /// let louds = LoudsBuilder::from_bit_string(BitString::new("10_110_0_0")).build();
+    /// let labeled = LabeledLouds::new(louds, vec!["root", "a", "c"]);
+    ///
+    /// let mut edit = labeled.edit();
+    /// edit.insert_child(LoudsNodeNum::new(1), 1, "b");
+    /// edit.relabel(LoudsNodeNum::new(1), "ROOT");
+    /// let edited = edit.apply();
+    ///
+    /// assert_eq!(edited.tree().labels(), &["ROOT", "a", "b", "c"]);
+    /// ```
+    pub fn edit(&self) -> LabeledLoudsEdit<'_, T> {
+        LabeledLoudsEdit {
+            labeled: self,
+            edit: self.louds().edit(),
+            inserted_labels: vec![],
+            relabels: HashMap::new(),
+        }
+    }
+}
+
+impl<'a, T: Clone> LabeledLoudsEdit<'a, T> {
+    /// Inserts a leaf labeled `label`. See [LoudsEdit::insert_child()](struct.LoudsEdit.html#method.insert_child).
+    ///
+    /// # Panics
+    /// - `parent` does not exist in the original tree.
+    /// - _`position` > <u>the number of children of `parent`</u>_.
+    pub fn insert_child(
+        &mut self,
+        parent: impl Borrow<LoudsNodeNum>,
+        position: u64,
+        label: T,
+    ) -> usize {
+        let parent = parent.borrow();
+        let insertion = self.edit.insert_child(parent, position);
+        self.inserted_labels.push(label);
+        insertion
+    }
+
+    /// Removes `node` and all its descendants. See [LoudsEdit::remove_subtree()](struct.LoudsEdit.html#method.remove_subtree).
+    ///
+    /// # Panics
+    /// - `node` does not exist in the original tree.
+    /// - `node` is the root.
+    pub fn remove_subtree(&mut self, node: impl Borrow<LoudsNodeNum>) {
+        let node = node.borrow();
+        self.edit.remove_subtree(node);
+    }
+
+    /// Replaces the label of original `node` with `label`.
+    ///
+    /// # Panics
+    /// `node` does not exist in the original tree.
+    pub fn relabel(&mut self, node: impl Borrow<LoudsNodeNum>, label: T) {
+        let node = node.borrow();
+        self.labeled.louds().validate_node_num(node);
+        self.relabels.insert(node.value(), label);
+    }
+
+    /// Builds the edited tree with labels. See [LoudsEdit::apply()](struct.LoudsEdit.html#method.apply).
+    pub fn apply(&self) -> LoudsEdited<LabeledLouds<T>> {
+        let (louds, order) = self.edit.plan();
+        let labels = order
+            .iter()
+            .map(|node| match node {
+                EditedNode::Old(node_num) => self
+                    .relabels
+                    .get(node_num)
+                    .unwrap_or_else(|| self.labeled.label(LoudsNodeNum::new(*node_num)))
+                    .clone(),
+                EditedNode::Inserted(insertion) => self.inserted_labels[*insertion].clone(),
+            })
+            .collect();
+
+        LoudsEdited::new(
+            LabeledLouds::new(louds, labels),
+            order,
+            self.labeled.louds().nodes_cnt(),
+            self.inserted_labels.len(),
+        )
+    }
+}
+
+impl<L> LoudsEdited<L> {
+    fn new(tree: L, order: Vec<EditedNode>, old_nodes_cnt: u64, inserts_cnt: usize) -> Self {
+        let mut old_to_new = vec![None; old_nodes_cnt as usize];
+        let mut inserted = vec![None; inserts_cnt];
+        for (k, node) in order.iter().enumerate() {
+            let new_node_num = Some(k as u64 + 1);
+            match node {
+                EditedNode::Old(node_num) => old_to_new[*node_num as usize - 1] = new_node_num,
+                EditedNode::Inserted(insertion) => inserted[*insertion] = new_node_num,
+            }
+        }
+        Self {
+            tree,
+            old_to_new,
+            inserted,
+        }
+    }
+
+    /// Returns the edited tree.
+    pub fn tree(&self) -> &L {
+        &self.tree
+    }
+
+    /// Returns the edited tree, dropping the mapping.
+    pub fn into_tree(self) -> L {
+        self.tree
+    }
+
+    /// Returns the new NodeNum of original `node`, or `None` if it was removed.
+    ///
+    /// # Panics
+    /// `node` does not exist in the original tree.
+    pub fn new_node_num(&self, node: impl Borrow<LoudsNodeNum>) -> Option<LoudsNodeNum> {
+        let node = node.borrow();
+        assert!(
+            node.value() > 0 && node.value() <= self.old_to_new.len() as u64,
+            "NodeNum({}) does not exist in the original LOUDS",
+            node.value()
+        );
+        self.old_to_new[node.value() as usize - 1].map(LoudsNodeNum::new)
+    }
+
+    /// Returns the NodeNum of the leaf inserted by `insertion`, or `None` if it was removed with its parent.
+    ///
+    /// # Panics
+    /// `insertion` is not returned by `insert_child()`.
+    pub fn inserted_node_num(&self, insertion: usize) -> Option<LoudsNodeNum> {
+        self.inserted[insertion].map(LoudsNodeNum::new)
+    }
+}
+
+#[cfg(test)]
+mod apply_success_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, inserts, removes, expected_louds, expected_old_to_new, expected_inserted): (&str, Vec<(u64, u64)>, Vec<u64>, &str, Vec<Option<u64>>, Vec<Option<u64>>) = $value;
+                let louds = LoudsBuilder::from_bit_string(BitString::new(in_s)).build();
+
+                let mut edit = louds.edit();
+                let insertions: Vec<usize> = inserts
+                    .iter()
+                    .map(|(parent, position)| edit.insert_child(LoudsNodeNum::new(*parent), *position))
+                    .collect();
+                for node in removes {
+                    edit.remove_subtree(LoudsNodeNum::new(node));
+                }
+                let edited = edit.apply();
+
+                assert_eq!(format!("{}", edited.tree()), expected_louds);
+                for (k, expected) in expected_old_to_new.into_iter().enumerate() {
+                    assert_eq!(edited.new_node_num(LoudsNodeNum::new(k as u64 + 1)), expected.map(LoudsNodeNum::new));
+                }
+                for (insertion, expected) in insertions.into_iter().zip(expected_inserted) {
+                    assert_eq!(edited.inserted_node_num(insertion), expected.map(LoudsNodeNum::new));
+                }
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: ("10_0", vec!(), vec!(), "10_0 (nodes: 1)", vec!(Some(1)), vec!()),
+        t2: ("10_0", vec!((1, 0)), vec!(), "10_10_0 (nodes: 2)", vec!(Some(1)), vec!(Some(2))),
+        t3: ("10_0", vec!((1, 0), (1, 0)), vec!(), "10_110_0_0 (nodes: 3)", vec!(Some(1)), vec!(Some(2), Some(3))),
+        t4: ("10_10_0", vec!((1, 0), (1, 1), (2, 0)), vec!(), "10_1110_0_10_0_0 (nodes: 5)", vec!(Some(1), Some(3)), vec!(Some(2), Some(4), Some(5))),
+        t5: ("10_10_0", vec!((2, 0)), vec!(2), "10_0 (nodes: 1)", vec!(Some(1), None), vec!(None)),
+        t6: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!(), vec!(), "10_1110_10_0_1110_0_0_10_110_0_0_0 (nodes: 11)", vec!(Some(1), Some(2), Some(3), Some(4), Some(5), Some(6), Some(7), Some(8), Some(9), Some(10), Some(11)), vec!()),
+        t7: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!(), vec!(4), "10_110_10_0_0 (nodes: 4)", vec!(Some(1), Some(2), Some(3), None, Some(4), None, None, None, None, None, None), vec!()),
+        t8: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!((1, 3)), vec!(2), "10_1110_0_1110_0_0_10_110_0_0_0 (nodes: 10)", vec!(Some(1), None, Some(2), Some(3), None, Some(5), Some(6), Some(7), Some(8), Some(9), Some(10)), vec!(Some(4))),
+        t9: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!((4, 1), (4, 0), (4, 1), (8, 2), (3, 0)), vec!(7), "10_1110_10_10_111110_0_0_0_0_0_0_1110_0_0_0 (nodes: 14)", vec!(Some(1), Some(2), Some(3), Some(4), Some(5), Some(8), None, Some(11), None, Some(12), Some(13)), vec!(Some(9), Some(7), Some(10), Some(14), Some(6))),
+        t10: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!((8, 1)), vec!(8), "10_1110_10_0_110_0_0_10_0 (nodes: 8)", vec!(Some(1), Some(2), Some(3), Some(4), Some(5), Some(6), Some(7), None, Some(8), None, None), vec!(None)),
+        t11: ("10_1110_10_0_1110_0_0_10_110_0_0_0", vec!((5, 0), (11, 0)), vec!(2, 3, 6, 7, 9, 10), "10_10_10_10_10_0 (nodes: 5)", vec!(Some(1), None, None, Some(2), None, None, None, Some(3), None, None, Some(4)), vec!(None, Some(5))),
+    }
+}
+
+#[cfg(test)]
+mod insert_child_failure_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, parent, position) = $value;
+                let louds = LoudsBuilder::from_bit_string(BitString::new(in_s)).build();
+                let _ = louds.edit().insert_child(LoudsNodeNum::new(parent), position);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", 0, 0),
+        t1_2: ("10_0", 2, 0),
+        t1_3: ("10_0", 1, 1),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, 4),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, 1),
+        t3_3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 12, 0),
+    }
+}
+
+#[cfg(test)]
+mod remove_subtree_failure_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, node) = $value;
+                let louds = LoudsBuilder::from_bit_string(BitString::new(in_s)).build();
+                louds.edit().remove_subtree(LoudsNodeNum::new(node));
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", 0),
+        t1_2: ("10_0", 1),
+        t1_3: ("10_0", 2),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 12),
+    }
+}
+
+#[cfg(test)]
+mod labeled_apply_success_tests {
+    use crate::{BitString, LabeledLouds, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, labels, inserts, removes, relabels, expected_louds, expected_labels): (&str, Vec<char>, Vec<(u64, u64, char)>, Vec<u64>, Vec<(u64, char)>, &str, Vec<char>) = $value;
+                let louds = LoudsBuilder::from_bit_string(BitString::new(in_s)).build();
+                let labeled = LabeledLouds::new(louds, labels);
+
+                let mut edit = labeled.edit();
+                for (parent, position, label) in inserts {
+                    edit.insert_child(LoudsNodeNum::new(parent), position, label);
+                }
+                for node in removes {
+                    edit.remove_subtree(LoudsNodeNum::new(node));
+                }
+                for (node, label) in relabels {
+                    edit.relabel(LoudsNodeNum::new(node), label);
+                }
+                let edited = edit.apply().into_tree();
+
+                assert_eq!(format!("{}", edited.louds()), expected_louds);
+                assert_eq!(edited.labels(), &expected_labels[..]);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: ("10_0", vec!('a'), vec!(), vec!(), vec!((1, 'z')), "10_0 (nodes: 1)", vec!('z')),
+        t2: ("10_0", vec!('a'), vec!((1, 0, 'b'), (1, 0, 'c')), vec!(), vec!(), "10_110_0_0 (nodes: 3)", vec!('a', 'b', 'c')),
+        t3: (
+            "10_1110_10_0_1110_0_0_10_110_0_0_0",
+            vec!('a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k'),
+            vec!((1, 3, 'x'), (8, 1, 'y')),
+            vec!(2),
+            vec!((8, 'H'), (5, 'E')),
+            "10_1110_0_1110_0_0_10_1110_0_0_0_0 (nodes: 11)",
+            vec!('a', 'c', 'd', 'x', 'f', 'g', 'H', 'i', 'j', 'y', 'k')
+        ),
+    }
+}
+
+#[cfg(test)]
+mod relabel_failure_tests {
+    use crate::{BitString, LabeledLouds, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, labels, node): (&str, Vec<u64>, u64) = $value;
+                let louds = LoudsBuilder::from_bit_string(BitString::new(in_s)).build();
+                let labeled = LabeledLouds::new(louds, labels);
+                labeled.edit().relabel(LoudsNodeNum::new(node), 0);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", vec!(1), 0),
+        t1_2: ("10_0", vec!(1), 2),
+    }
+}
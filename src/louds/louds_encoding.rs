@@ -0,0 +1,300 @@
+use super::{Louds, LoudsBuilder, LoudsNodeNum};
+use crate::internal_data_structure::raw_bit_vector::RawBitVector;
+use crate::succinct_bit_vector::SuccinctBitVectorBuilder;
+use crate::SuccinctBitVector;
+
+impl Louds {
+    /// Encodes this tree in BP (balanced parentheses): visiting nodes in DFS preorder, '1' (open) on entering a node and '0' (close) on leaving it.
+    ///
+    /// Returns the _2N_ bits and the permutation from DFS order to BFS order: `node_nums[k]` is the NodeNum of the _k_-th node in DFS preorder.
+    ///
+    /// # Examples
+    /// ```
+    /// use succinct_rs::{BitString, LoudsBuilder, LoudsNodeNum};
+    ///
+    /// //    (1)
+    /// //     |---+
+    /// //    (2) (3)
+    /// //     |
+    /// //    (4)
+    /// let louds = LoudsBuilder::from_bit_string(BitString::new("10_110_10_0_0")).build();
+    /// let (bp, node_nums) = louds.to_bp();
+    ///
+    /// assert_eq!(format!("{}", bp), "11100100 (length: 8, popcount: 4)");
+    /// assert_eq!(node_nums.iter().map(|n| n.value()).collect::<Vec<u64>>(), vec!(1, 2, 4, 3));
+    ///
+    /// let (rebuilt, rebuilt_node_nums) = LoudsBuilder::from_bp(&bp);
+    /// assert_eq!(format!("{}", rebuilt.build()), format!("{}", louds));
+    /// assert_eq!(rebuilt_node_nums, node_nums);
+    /// ```
+    pub fn to_bp(&self) -> (SuccinctBitVector, Vec<LoudsNodeNum>) {
+        // Opens the root.
+        let mut bp = RawBitVector::from_length(1);
+        bp.set_bit(0);
+        let mut node_nums = vec![LoudsNodeNum::new(1)];
+
+        // Each entry is the NodeNum range of a node's unvisited children. Popping it closes the node.
+        let mut stack = vec![self.children_node_nums(1)];
+        while let Some(top) = stack.last_mut() {
+            let (first, end) = *top;
+            if first < end {
+                top.0 += 1;
+                bp.push(true);
+                node_nums.push(LoudsNodeNum::new(first));
+                stack.push(self.children_node_nums(first));
+            } else {
+                stack.pop();
+                bp.push(false);
+            }
+        }
+
+        (
+            SuccinctBitVectorBuilder::from_raw_bit_vector(bp).build(),
+            node_nums,
+        )
+    }
+
+    /// Encodes this tree in DFUDS (depth-first unary degree sequence): a leading '1' (open), then each node's degree in unary ('1's followed by a '0') in DFS preorder.
+    ///
+    /// Returns the _2N_ bits and the permutation from DFS order to BFS order: `node_nums[k]` is the NodeNum of the _k_-th node in DFS preorder.
+    ///
+    /// # Examples
+    /// ```
+    /// use succinct_rs::{BitString, LoudsBuilder, LoudsNodeNum};
+    ///
+    /// //    (1)
+    /// //     |---+
+    /// //    (2) (3)
+    /// //     |
+    /// //    (4)
+    /// let louds = LoudsBuilder::from_bit_string(BitString::new("10_110_10_0_0")).build();
+    /// let (dfuds, node_nums) = louds.to_dfuds();
+    ///
+    /// assert_eq!(format!("{}", dfuds), "11101000 (length: 8, popcount: 4)");
+    /// assert_eq!(node_nums.iter().map(|n| n.value()).collect::<Vec<u64>>(), vec!(1, 2, 4, 3));
+    ///
+    /// let (rebuilt, rebuilt_node_nums) = LoudsBuilder::from_dfuds(&dfuds);
+    /// assert_eq!(format!("{}", rebuilt.build()), format!("{}", louds));
+    /// assert_eq!(rebuilt_node_nums, node_nums);
+    /// ```
+    pub fn to_dfuds(&self) -> (SuccinctBitVector, Vec<LoudsNodeNum>) {
+        let mut dfuds = RawBitVector::from_length(1);
+        dfuds.set_bit(0);
+
+        let node_nums: Vec<LoudsNodeNum> = self.dfs_preorder().collect();
+        for node_num in &node_nums {
+            LoudsBuilder::add_node(&mut dfuds, self.degree(node_num) as usize);
+        }
+
+        (
+            SuccinctBitVectorBuilder::from_raw_bit_vector(dfuds).build(),
+            node_nums,
+        )
+    }
+}
+
+impl super::LoudsBuilder {
+    /// Prepares for building [Louds](struct.Louds.html) from BP (balanced parentheses), as encoded by [Louds::to_bp()](struct.Louds.html#method.to_bp).
+    ///
+    /// Returns the builder and the permutation from DFS order to BFS order: `node_nums[k]` is the NodeNum of the _k_-th node in DFS preorder.
+    ///
+    /// # Panics
+    /// If `bp` does not represent a tree:
+    ///
+    /// - `bp` is empty.
+    /// - Some '0' (close) has no matching '1' (open).
+    /// - The root is closed before the end of `bp`, or is not closed at all.
+    pub fn from_bp<S: AsRef<[u64]>>(bp: &SuccinctBitVector<S>) -> (Self, Vec<LoudsNodeNum>) {
+        assert!(bp.length() > 0, "BP must not be empty");
+
+        // children[k] are the DFS numbers of the k-th node's children.
+        let mut children: Vec<Vec<usize>> = vec![];
+        let mut open: Vec<usize> = vec![];
+        for i in 0..bp.length() {
+            if bp.access(i) {
+                assert!(
+                    i == 0 || !open.is_empty(),
+                    "At index {}, a node is opened after the root is closed",
+                    i
+                );
+                let k = children.len();
+                if let Some(&parent) = open.last() {
+                    children[parent].push(k);
+                }
+                children.push(vec![]);
+                open.push(k);
+            } else {
+                assert!(
+                    open.pop().is_some(),
+                    "At index {}, '0' (close) has no matching '1' (open)",
+                    i
+                );
+            }
+        }
+        assert!(open.is_empty(), "{} nodes are not closed", open.len());
+
+        LoudsBuilder::from_children_lists(&children, 0)
+    }
+
+    /// Prepares for building [Louds](struct.Louds.html) from DFUDS (depth-first unary degree sequence), as encoded by [Louds::to_dfuds()](struct.Louds.html#method.to_dfuds).
+    ///
+    /// Returns the builder and the permutation from DFS order to BFS order: `node_nums[k]` is the NodeNum of the _k_-th node in DFS preorder.
+    ///
+    /// # Panics
+    /// If `dfuds` does not represent a tree:
+    ///
+    /// - `dfuds` does not start from '1'.
+    /// - `dfuds` ends while some children are not given their degrees.
+    /// - `dfuds` continues after every node got its degree.
+    pub fn from_dfuds<S: AsRef<[u64]>>(dfuds: &SuccinctBitVector<S>) -> (Self, Vec<LoudsNodeNum>) {
+        assert!(
+            dfuds.length() > 0 && dfuds.access(0),
+            "DFUDS must start from '1'"
+        );
+
+        // children[k] are the DFS numbers of the k-th node's children.
+        let mut children: Vec<Vec<usize>> = vec![vec![]];
+        // Each entry is a node and the number of its children not visited yet.
+        let mut stack: Vec<(usize, usize)> = vec![];
+        let (mut degree, mut finished) = (0, false);
+        for i in 1..dfuds.length() {
+            if dfuds.access(i) {
+                degree += 1;
+                continue;
+            }
+
+            // The degree of node#k (in DFS order) ends at i.
+            let k = children.len() - 1;
+            assert!(
+                !finished,
+                "At index {}, DFUDS continues after all the {} nodes of the tree",
+                i,
+                k + 1
+            );
+            stack.push((k, degree));
+            degree = 0;
+            while let Some(&(_, 0)) = stack.last() {
+                stack.pop();
+            }
+            if let Some(top) = stack.last_mut() {
+                top.1 -= 1;
+                let child = children.len();
+                children[top.0].push(child);
+                children.push(vec![]);
+            } else {
+                finished = true;
+            }
+        }
+        assert!(
+            finished && degree == 0,
+            "DFUDS ends while some children are not given their degrees"
+        );
+
+        LoudsBuilder::from_children_lists(&children, 0)
+    }
+}
+
+#[cfg(test)]
+mod to_bp_dfuds_success_tests {
+    use crate::{BitString, LoudsBuilder, SuccinctBitVectorBuilder};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, expected_bp, expected_dfuds, expected_node_nums): (&str, &str, &str, Vec<u64>) = $value;
+                let louds = LoudsBuilder::from_bit_string(BitString::new(in_s)).build();
+
+                let (bp, node_nums) = louds.to_bp();
+                let bits: String = (0..bp.length()).map(|i| if bp.access(i) { '1' } else { '0' }).collect();
+                assert_eq!(bits, BitString::new(expected_bp).str());
+                assert_eq!(node_nums.iter().map(|n| n.value()).collect::<Vec<u64>>(), expected_node_nums);
+
+                let (dfuds, node_nums) = louds.to_dfuds();
+                let bits: String = (0..dfuds.length()).map(|i| if dfuds.access(i) { '1' } else { '0' }).collect();
+                assert_eq!(bits, BitString::new(expected_dfuds).str());
+                assert_eq!(node_nums.iter().map(|n| n.value()).collect::<Vec<u64>>(), expected_node_nums);
+
+                let bp = SuccinctBitVectorBuilder::from_bit_string(BitString::new(expected_bp)).build();
+                let (builder, node_nums) = LoudsBuilder::from_bp(&bp);
+                assert_eq!(format!("{}", builder.build()), format!("{}", louds));
+                assert_eq!(node_nums.iter().map(|n| n.value()).collect::<Vec<u64>>(), expected_node_nums);
+
+                let dfuds = SuccinctBitVectorBuilder::from_bit_string(BitString::new(expected_dfuds)).build();
+                let (builder, node_nums) = LoudsBuilder::from_dfuds(&dfuds);
+                assert_eq!(format!("{}", builder.build()), format!("{}", louds));
+                assert_eq!(node_nums.iter().map(|n| n.value()).collect::<Vec<u64>>(), expected_node_nums);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: ("10_0", "10", "1_0", vec!(1)),
+        t2: ("10_10_0", "1100", "1_10_0", vec!(1, 2)),
+        t3: (
+            "10_1110_10_0_1110_0_0_10_110_0_0_0",
+            "1110010110110011010000",
+            "1_1110_10_0_0_1110_0_10_0_110_0_0",
+            vec!(1, 2, 5, 3, 4, 6, 7, 9, 8, 10, 11)
+        ),
+    }
+}
+
+#[cfg(test)]
+mod from_bp_failure_tests {
+    use crate::{BitString, LoudsBuilder, SuccinctBitVectorBuilder};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let in_s = $value;
+                let bp = SuccinctBitVectorBuilder::from_bit_string(BitString::new(in_s)).build();
+                let _ = LoudsBuilder::from_bp(&bp);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: "0",
+        t2: "1",
+        t3: "01",
+        t4: "100",
+        t5: "1010",
+        t6: "110",
+    }
+}
+
+#[cfg(test)]
+mod from_dfuds_failure_tests {
+    use crate::{BitString, LoudsBuilder, SuccinctBitVectorBuilder};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let in_s = $value;
+                let dfuds = SuccinctBitVectorBuilder::from_bit_string(BitString::new(in_s)).build();
+                let _ = LoudsBuilder::from_dfuds(&dfuds);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: "0",
+        t2: "1",
+        t3: "0_0",
+        t4: "1_0_0",
+        t5: "1_10_0_0",
+        t6: "1_10",
+        t7: "1_0_1",
+    }
+}
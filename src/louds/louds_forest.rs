@@ -0,0 +1,372 @@
+use super::{Louds, LoudsChildren, LoudsForest, LoudsForestRoots, LoudsIndex, LoudsNodeNum};
+use crate::internal_data_structure::raw_bit_vector::RawBitVector;
+use crate::succinct_bit_vector::SuccinctBitVectorBuilder;
+use crate::SuccinctBitVector;
+use std::borrow::Borrow;
+use std::fmt;
+use std::ops::Range;
+
+impl LoudsForest {
+    /// Constructor from a valid concatenation of LBSs. Finds the boundaries of trees.
+    pub(super) fn from_lbs(lbs: SuccinctBitVector) -> Self {
+        let mut tree_starts = vec![];
+
+        // A tree ends when its '0's outnumber its '1's.
+        let (mut cnt1, mut excess, mut in_tree) = (0u64, 0i64, false);
+        for i in 0..lbs.length() {
+            if !in_tree {
+                tree_starts.push(cnt1 + 1);
+                in_tree = true;
+            }
+            if lbs.access(i) {
+                cnt1 += 1;
+                excess += 1;
+            } else {
+                excess -= 1;
+            }
+            if excess == -1 {
+                excess = 0;
+                in_tree = false;
+            }
+        }
+        tree_starts.push(cnt1 + 1);
+        LoudsForest { lbs, tree_starts }
+    }
+
+    /// Returns the number of trees.
+    pub fn trees_cnt(&self) -> u64 {
+        self.tree_starts.len() as u64 - 1
+    }
+
+    /// Returns the number of nodes in all the trees.
+    pub fn nodes_cnt(&self) -> u64 {
+        self.tree_starts[self.tree_starts.len() - 1] - 1
+    }
+
+    /// Returns an iterator over the roots of the trees, from the first tree to the last.
+    pub fn roots(&self) -> LoudsForestRoots<'_> {
+        LoudsForestRoots {
+            tree_starts: self.tree_starts[..self.tree_starts.len() - 1].iter(),
+        }
+    }
+
+    /// Returns the (0-origin) number of the tree which `node_num` belongs to.
+    ///
+    /// It binary-searches the tree boundaries and takes _O(log T)_ time where _T_ is the number of trees.
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this forest.
+    pub fn tree_of(&self, node_num: impl Borrow<LoudsNodeNum>) -> u64 {
+        let node_num = node_num.borrow();
+        self.validate_node_num(node_num);
+        match self.tree_starts.binary_search(&node_num.value()) {
+            Ok(t) => t as u64,
+            Err(t) => t as u64 - 1,
+        }
+    }
+
+    /// Returns the range of NodeNums of `tree`-th tree.
+    ///
+    /// # Panics
+    /// When _`tree` >= `self.trees_cnt()`_.
+    pub fn tree_node_nums(&self, tree: u64) -> Range<LoudsNodeNum> {
+        self.validate_tree(tree);
+        LoudsNodeNum::new(self.tree_starts[tree as usize])
+            ..LoudsNodeNum::new(self.tree_starts[tree as usize + 1])
+    }
+
+    /// Returns whether `node_num` is the root of a tree.
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this forest.
+    pub fn is_root(&self, node_num: impl Borrow<LoudsNodeNum>) -> bool {
+        let node_num = node_num.borrow();
+        self.validate_node_num(node_num);
+        self.tree_starts.binary_search(&node_num.value()).is_ok()
+    }
+
+    /// Copies `tree`-th tree out as a [Louds](struct.Louds.html). NodeNum _k_ in the forest is NodeNum _k - `self.tree_node_nums(tree).start.value()` + 1_ in it.
+    ///
+    /// # Panics
+    /// When _`tree` >= `self.trees_cnt()`_.
+    pub fn tree(&self, tree: u64) -> Louds {
+        let start = self.lbs_start(tree);
+        let nodes = self.tree_node_nums(tree);
+        let length = 2 * (nodes.end.value() - nodes.start.value()) + 1;
+
+        let mut rbv = RawBitVector::from_length(length);
+        for i in 0..length {
+            if self.lbs.access(start + i) {
+                rbv.set_bit(i);
+            }
+        }
+        Louds::from_lbs(SuccinctBitVectorBuilder::from_raw_bit_vector(rbv).build())
+    }
+
+    /// # Panics
+    /// `node_num` does not exist in this forest.
+    pub fn node_num_to_index(&self, node_num: impl Borrow<LoudsNodeNum>) -> LoudsIndex {
+        let node_num = node_num.borrow();
+        self.validate_node_num(node_num);
+        LoudsIndex::new(self.lbs.select(node_num.value()).unwrap())
+    }
+
+    /// # Panics
+    /// `index` does not point to any node in this forest.
+    pub fn index_to_node_num(&self, index: impl Borrow<LoudsIndex>) -> LoudsNodeNum {
+        let index = index.borrow();
+        assert!(
+            self.lbs.access(index.value()),
+            "LBS[index={:?}] must be '1'",
+            index,
+        );
+        LoudsNodeNum::new(self.lbs.rank(index.value()))
+    }
+
+    /// Returns the parent of `node_num`, or `None` if it is a root.
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this forest.
+    pub fn parent(&self, node_num: impl Borrow<LoudsNodeNum>) -> Option<LoudsNodeNum> {
+        let node_num = node_num.borrow();
+        let tree = self.tree_of(node_num);
+        if self.tree_starts[tree as usize] == node_num.value() {
+            return None;
+        }
+        // Each preceding tree has an extra '0' of its virtual root.
+        let index = self.lbs.select(node_num.value()).unwrap();
+        Some(LoudsNodeNum::new(self.lbs.rank0(index) - tree))
+    }
+
+    /// Returns an iterator over the indices of `node_num`'s children, from left to right.
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this forest.
+    pub fn children(&self, node_num: impl Borrow<LoudsNodeNum>) -> LoudsChildren<'_> {
+        let node_num = node_num.borrow();
+        let tree = self.tree_of(node_num);
+        let start = self.lbs.select0(node_num.value() + tree).unwrap() + 1;
+        LoudsChildren::new(&self.lbs, start)
+    }
+
+    /// Returns the index where `tree`-th tree's LBS starts: each preceding tree of _n_ nodes takes _2n + 1_ bits.
+    fn lbs_start(&self, tree: u64) -> u64 {
+        self.validate_tree(tree);
+        2 * (self.tree_starts[tree as usize] - 1) + tree
+    }
+
+    /// # Panics
+    /// `node_num` does not exist in this forest.
+    fn validate_node_num(&self, node_num: &LoudsNodeNum) {
+        assert!(
+            node_num.value() > 0 && node_num.value() <= self.nodes_cnt(),
+            "NodeNum({}) does not exist in this forest",
+            node_num.value(),
+        );
+    }
+
+    /// # Panics
+    /// When _`tree` >= `self.trees_cnt()`_.
+    fn validate_tree(&self, tree: u64) {
+        assert!(
+            tree < self.trees_cnt(),
+            "tree = {} must be smaller than {} (self.trees_cnt())",
+            tree,
+            self.trees_cnt()
+        );
+    }
+}
+
+impl fmt::Debug for LoudsForest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LoudsForest")
+            .field("trees", &self.trees_cnt())
+            .field("nodes", &self.nodes_cnt())
+            .field("lbs", &self.lbs)
+            .finish()
+    }
+}
+
+impl<'a> Iterator for LoudsForestRoots<'a> {
+    type Item = LoudsNodeNum;
+
+    fn next(&mut self) -> Option<LoudsNodeNum> {
+        self.tree_starts.next().map(|root| LoudsNodeNum::new(*root))
+    }
+}
+
+#[cfg(test)]
+mod from_lbs_success_tests {
+    use crate::{BitString, LoudsForestBuilder};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, expected_roots, expected_nodes_cnt): (&str, Vec<u64>, u64) = $value;
+                let forest = LoudsForestBuilder::from_bit_string(BitString::new(in_s)).build();
+                assert_eq!(forest.roots().map(|r| r.value()).collect::<Vec<u64>>(), expected_roots);
+                assert_eq!(forest.trees_cnt(), expected_roots.len() as u64);
+                assert_eq!(forest.nodes_cnt(), expected_nodes_cnt);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: ("10_0", vec!(1), 1),
+        t2: ("10_0_10_0", vec!(1, 2), 2),
+        t3: ("10_10_0_10_0_10_110_0_0", vec!(1, 3, 4), 6),
+        t4: ("10_1110_10_0_1110_0_0_10_110_0_0_0_10_0", vec!(1, 12), 12),
+    }
+}
+
+#[cfg(test)]
+mod tree_of_success_tests {
+    use crate::{BitString, LoudsForestBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, node_num, expected_tree, expected_is_root): (&str, u64, u64, bool) = $value;
+                let forest = LoudsForestBuilder::from_bit_string(BitString::new(in_s)).build();
+                let node_num = LoudsNodeNum::new(node_num);
+                assert_eq!(forest.tree_of(node_num), expected_tree);
+                assert!(forest.tree_node_nums(expected_tree).contains(&node_num));
+                assert_eq!(forest.is_root(node_num), expected_is_root);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", 1, 0, true),
+
+        t2_1: ("10_10_0_10_0_10_110_0_0", 1, 0, true),
+        t2_2: ("10_10_0_10_0_10_110_0_0", 2, 0, false),
+        t2_3: ("10_10_0_10_0_10_110_0_0", 3, 1, true),
+        t2_4: ("10_10_0_10_0_10_110_0_0", 4, 2, true),
+        t2_5: ("10_10_0_10_0_10_110_0_0", 5, 2, false),
+        t2_6: ("10_10_0_10_0_10_110_0_0", 6, 2, false),
+    }
+}
+
+#[cfg(test)]
+mod tree_of_failure_tests {
+    use crate::{BitString, LoudsForestBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_node_not_found_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, node_num) = $value;
+                let forest = LoudsForestBuilder::from_bit_string(BitString::new(in_s)).build();
+                let _ = forest.tree_of(LoudsNodeNum::new(node_num));
+            }
+        )*
+        }
+    }
+
+    parameterized_node_not_found_tests! {
+        t1_1: ("10_0", 0),
+        t1_2: ("10_0", 2),
+
+        t2_1: ("10_10_0_10_0_10_110_0_0", 0),
+        t2_2: ("10_10_0_10_0_10_110_0_0", 7),
+    }
+}
+
+#[cfg(test)]
+mod tree_success_tests {
+    use crate::{BitString, LoudsForestBuilder};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, tree, expected_louds): (&str, u64, &str) = $value;
+                let forest = LoudsForestBuilder::from_bit_string(BitString::new(in_s)).build();
+                assert_eq!(format!("{}", forest.tree(tree)), expected_louds);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", 0, "10_0 (nodes: 1)"),
+
+        t2_1: ("10_10_0_10_0_10_110_0_0", 0, "10_10_0 (nodes: 2)"),
+        t2_2: ("10_10_0_10_0_10_110_0_0", 1, "10_0 (nodes: 1)"),
+        t2_3: ("10_10_0_10_0_10_110_0_0", 2, "10_110_0_0 (nodes: 3)"),
+    }
+}
+
+#[cfg(test)]
+mod tree_failure_tests {
+    use crate::{BitString, LoudsForestBuilder};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, tree) = $value;
+                let forest = LoudsForestBuilder::from_bit_string(BitString::new(in_s)).build();
+                let _ = forest.tree(tree);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", 1),
+        t2_1: ("10_10_0_10_0_10_110_0_0", 3),
+    }
+}
+
+#[cfg(test)]
+mod parent_success_tests {
+    use crate::{BitString, LoudsForestBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, node_num, expected_parent, expected_children): (&str, u64, Option<u64>, Vec<u64>) = $value;
+                let forest = LoudsForestBuilder::from_bit_string(BitString::new(in_s)).build();
+                let node_num = LoudsNodeNum::new(node_num);
+                assert_eq!(forest.parent(node_num), expected_parent.map(LoudsNodeNum::new));
+
+                let children: Vec<u64> = forest
+                    .children(&node_num)
+                    .map(|index| forest.index_to_node_num(index).value())
+                    .collect();
+                assert_eq!(children, expected_children);
+                for child in children {
+                    let index = forest.node_num_to_index(LoudsNodeNum::new(child));
+                    assert_eq!(forest.index_to_node_num(index), LoudsNodeNum::new(child));
+                }
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", 1, None, vec!()),
+
+        t2_1: ("10_10_0_10_0_10_110_0_0", 1, None, vec!(2)),
+        t2_2: ("10_10_0_10_0_10_110_0_0", 2, Some(1), vec!()),
+        t2_3: ("10_10_0_10_0_10_110_0_0", 3, None, vec!()),
+        t2_4: ("10_10_0_10_0_10_110_0_0", 4, None, vec!(5, 6)),
+        t2_5: ("10_10_0_10_0_10_110_0_0", 5, Some(4), vec!()),
+        t2_6: ("10_10_0_10_0_10_110_0_0", 6, Some(4), vec!()),
+    }
+}
@@ -0,0 +1,176 @@
+use super::{Louds, LoudsForest, LoudsForestBuilder};
+use crate::internal_data_structure::raw_bit_vector::RawBitVector;
+use crate::succinct_bit_vector::SuccinctBitVectorBuilder;
+use crate::BitString;
+
+impl super::LoudsForestBuilder {
+    /// Prepares for building [LoudsForest](struct.LoudsForest.html) from the concatenation of trees' LBSs.
+    ///
+    /// # Panics
+    /// If `bs` is not a concatenation of one or more LBSs. Each of them must satisfy the conditions described in [LoudsBuilder::from_bit_string()](struct.LoudsBuilder.html#method.from_bit_string).
+    pub fn from_bit_string(bs: BitString) -> Self {
+        LoudsForestBuilder::validate_forest_lbs(&bs);
+        let bv_builder = SuccinctBitVectorBuilder::from_bit_string(bs);
+        LoudsForestBuilder { bv_builder }
+    }
+
+    /// Prepares for building [LoudsForest](struct.LoudsForest.html) of `trees` in this order.
+    ///
+    /// # Panics
+    /// When `trees` is empty.
+    pub fn from_trees(trees: &[Louds]) -> Self {
+        assert!(!trees.is_empty(), "A forest must have at least 1 tree");
+
+        let length = trees.iter().map(|tree| tree.lbs.length()).sum();
+        let mut lbs = RawBitVector::from_length(length);
+        let mut offset = 0;
+        for tree in trees {
+            for i in 0..tree.lbs.length() {
+                if tree.lbs.access(i) {
+                    lbs.set_bit(offset + i);
+                }
+            }
+            offset += tree.lbs.length();
+        }
+        LoudsForestBuilder {
+            bv_builder: SuccinctBitVectorBuilder::from_raw_bit_vector(lbs),
+        }
+    }
+
+    /// Build [LoudsForest](struct.LoudsForest.html).
+    ///
+    /// It takes _O(N)_ time to find the boundaries of trees where _N_ is the length of LBS.
+    pub fn build(&self) -> LoudsForest {
+        let bv = self.bv_builder.build();
+        LoudsForest::from_lbs(bv)
+    }
+
+    /// Checks if `bs` is a concatenation of LBSs.
+    fn validate_forest_lbs(bs: &BitString) {
+        let s = bs.str();
+        assert!(!s.is_empty(), "A forest must have at least 1 tree");
+
+        // Counts in the current tree.
+        let (mut cnt0, mut cnt1) = (0u64, 0u64);
+        for (i, ch) in s.char_indices() {
+            if cnt0 == 0 && cnt1 == 0 {
+                assert!(
+                    s[i..].starts_with("10"),
+                    "The tree starting at index {} must start from \"10\"",
+                    i
+                );
+            }
+            match ch {
+                '0' => cnt0 += 1,
+                '1' => cnt1 += 1,
+                c => panic!("LBS contains invalid character '{}'", c),
+            }
+            if cnt0 == cnt1 + 1 {
+                // This tree ends.
+                cnt0 = 0;
+                cnt1 = 0;
+            }
+        }
+
+        assert!(
+            cnt0 == 0 && cnt1 == 0,
+            "The last tree lacks {} '0'",
+            cnt1 + 1 - cnt0
+        );
+    }
+}
+
+#[cfg(test)]
+mod validate_forest_lbs_success_tests {
+    use crate::{BitString, LoudsForestBuilder};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let s = $value;
+                let bs = BitString::new(s);
+                LoudsForestBuilder::validate_forest_lbs(&bs);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: "10_0",
+        t2: "10_0_10_0",
+        t3: "10_10_0_10_0_10_110_0_0",
+        t4: "10_1110_10_0_1110_0_0_10_110_0_0_0_10_0",
+    }
+}
+
+#[cfg(test)]
+mod validate_forest_lbs_failure_tests {
+    use crate::{BitString, LoudsForestBuilder};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let s = $value;
+                let bs = BitString::new(s);
+                LoudsForestBuilder::validate_forest_lbs(&bs);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: "0",
+        t2: "1",
+        t3: "10",
+        t4: "10_0_0",
+        t5: "10_0_1",
+        t6: "10_0_10",
+        t7: "10_0_11_0_0",
+        t8: "10_10_0_10_1",
+    }
+}
+
+#[cfg(test)]
+mod from_trees_success_tests {
+    use crate::{BitString, LoudsBuilder, LoudsForestBuilder};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_trees, expected_forest): (Vec<&str>, &str) = $value;
+                let trees: Vec<_> = in_trees
+                    .iter()
+                    .map(|s| LoudsBuilder::from_bit_string(BitString::new(s)).build())
+                    .collect();
+                let forest = LoudsForestBuilder::from_trees(&trees).build();
+                let expected = LoudsForestBuilder::from_bit_string(BitString::new(expected_forest)).build();
+                assert_eq!(format!("{:?}", forest), format!("{:?}", expected));
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: (vec!("10_0"), "10_0"),
+        t2: (vec!("10_0", "10_10_0"), "10_0_10_10_0"),
+        t3: (vec!("10_1110_10_0_1110_0_0_10_110_0_0_0", "10_0", "10_110_0_0"), "10_1110_10_0_1110_0_0_10_110_0_0_0_10_0_10_110_0_0"),
+    }
+}
+
+#[cfg(test)]
+mod from_trees_failure_tests {
+    use crate::LoudsForestBuilder;
+
+    #[test]
+    #[should_panic]
+    fn empty() {
+        let _ = LoudsForestBuilder::from_trees(&[]);
+    }
+}
@@ -7,3 +7,15 @@ impl super::LoudsIndex {
         self.value
     }
 }
+
+impl From<u64> for super::LoudsIndex {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<super::LoudsIndex> for u64 {
+    fn from(x: super::LoudsIndex) -> Self {
+        x.value()
+    }
+}
@@ -7,3 +7,72 @@ impl super::LoudsNodeNum {
         self.value
     }
 }
+
+impl From<u64> for super::LoudsNodeNum {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<super::LoudsNodeNum> for u64 {
+    fn from(x: super::LoudsNodeNum) -> Self {
+        x.value()
+    }
+}
+
+#[cfg(test)]
+mod traits_tests {
+    use crate::{BitString, LoudsBuilder, LoudsIndex, LoudsNodeNum};
+    use std::collections::HashSet;
+
+    #[test]
+    fn from_into_u64() {
+        let node_num: LoudsNodeNum = 8.into();
+        assert_eq!(node_num, LoudsNodeNum::new(8));
+        assert_eq!(u64::from(node_num), 8);
+
+        let index = LoudsIndex::from(17);
+        assert_eq!(index, LoudsIndex::new(17));
+        let value: u64 = index.into();
+        assert_eq!(value, 17);
+    }
+
+    #[test]
+    fn hash_and_ord() {
+        let louds =
+            LoudsBuilder::from_bit_string(BitString::new("10_1110_10_0_1110_0_0_10_110_0_0_0"))
+                .build();
+
+        let mut node_nums: Vec<LoudsNodeNum> = louds.dfs_preorder().collect();
+        node_nums.sort();
+        assert_eq!(node_nums, louds.bfs().collect::<Vec<LoudsNodeNum>>());
+
+        let leaves: HashSet<LoudsNodeNum> = node_nums
+            .iter()
+            .copied()
+            .filter(|&n| louds.is_leaf(n))
+            .collect();
+        assert_eq!(leaves.len(), 6);
+        assert!(leaves.contains(&LoudsNodeNum::new(9)));
+
+        let mut indices: Vec<LoudsIndex> = node_nums
+            .iter()
+            .map(|&n| louds.node_num_to_index(n))
+            .collect();
+        indices.reverse();
+        assert_eq!(indices.iter().max(), Some(&LoudsIndex::new(18)));
+    }
+
+    #[test]
+    fn by_value_and_by_reference() {
+        let louds =
+            LoudsBuilder::from_bit_string(BitString::new("10_1110_10_0_1110_0_0_10_110_0_0_0"))
+                .build();
+        let node8 = LoudsNodeNum::new(8);
+
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        let by_reference = louds.parent_to_children(&node8);
+        assert_eq!(louds.parent_to_children(node8), by_reference);
+        assert_eq!(louds.lca(node8, LoudsNodeNum::new(9)), LoudsNodeNum::new(4));
+    }
+}
@@ -0,0 +1,70 @@
+use super::{Louds, LoudsNodeNum};
+use crate::OrdinalTree;
+use std::iter::Map;
+use std::ops::Range;
+
+impl OrdinalTree for Louds {
+    type Node = LoudsNodeNum;
+
+    /// Children have consecutive NodeNums, so they are a range found by two `select0`s.
+    type Children<'a> = Map<Range<u64>, fn(u64) -> LoudsNodeNum>;
+
+    fn root(&self) -> LoudsNodeNum {
+        LoudsNodeNum::new(1)
+    }
+
+    fn parent_node(&self, node: LoudsNodeNum) -> Option<LoudsNodeNum> {
+        if self.is_root(node) {
+            None
+        } else {
+            Some(self.child_to_parent(self.node_num_to_index(node)))
+        }
+    }
+
+    fn first_child_node(&self, node: LoudsNodeNum) -> Option<LoudsNodeNum> {
+        self.first_child(node)
+            .map(|index| self.index_to_node_num(index))
+    }
+
+    fn next_sibling_node(&self, node: LoudsNodeNum) -> Option<LoudsNodeNum> {
+        self.next_sibling(self.node_num_to_index(node))
+            .map(|index| self.index_to_node_num(index))
+    }
+
+    fn child_nodes(&self, node: LoudsNodeNum) -> Self::Children<'_> {
+        let (first, end) = self.children_node_nums(node.value());
+        (first..end).map(LoudsNodeNum::new as fn(u64) -> LoudsNodeNum)
+    }
+
+    fn nodes_cnt(&self) -> u64 {
+        Louds::nodes_cnt(self)
+    }
+
+    fn children_cnt(&self, node: LoudsNodeNum) -> u64 {
+        self.degree(node)
+    }
+
+    fn is_leaf_node(&self, node: LoudsNodeNum) -> bool {
+        self.is_leaf(node)
+    }
+
+    fn node_depth(&self, node: LoudsNodeNum) -> u64 {
+        self.depth(node)
+    }
+
+    fn lowest_common_ancestor(&self, a: LoudsNodeNum, b: LoudsNodeNum) -> LoudsNodeNum {
+        self.lca(a, b)
+    }
+
+    fn level_order(&self) -> Vec<LoudsNodeNum> {
+        self.bfs().collect()
+    }
+
+    fn preorder(&self) -> Vec<LoudsNodeNum> {
+        self.dfs_preorder().collect()
+    }
+
+    fn postorder(&self) -> Vec<LoudsNodeNum> {
+        self.dfs_postorder().collect()
+    }
+}
@@ -0,0 +1,48 @@
+use super::{Louds, LoudsBuilder};
+use crate::internal_data_structure::binary_io::invalid_data;
+use crate::internal_data_structure::serialization_header::{
+    read_header, write_header, SerializedKind, FLAG_DIRECTORY,
+};
+use crate::SuccinctBitVector;
+use std::io::{self, Read, Write};
+
+impl Louds {
+    /// Serializes LBS together with its rank/select directory, so that [deserialize()](#method.deserialize) does not rebuild it.
+    ///
+    /// # Examples
+    /// ```
+    /// use succinct_rs::{BitString, Louds, LoudsBuilder, LoudsIndex, LoudsNodeNum};
+    ///
+    /// let louds = LoudsBuilder::from_bit_string(BitString::new("10_1110_10_0_1110_0_0_10_110_0_0_0")).build();
+    ///
+    /// let mut compact = vec![];
+    /// louds.serialize_compact(&mut compact).unwrap();
+    ///
+    /// let louds = Louds::deserialize(&mut &compact[..]).unwrap();
+    /// assert_eq!(louds.parent_to_children(LoudsNodeNum::new(8)), vec!(LoudsIndex::new(17), LoudsIndex::new(18)));
+    /// ```
+    pub fn serialize<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        write_header(w, SerializedKind::Louds, FLAG_DIRECTORY)?;
+        self.lbs.serialize_body(w, true)
+    }
+
+    /// Serializes only LBS. [deserialize()](#method.deserialize) rebuilds the rank/select directory in _O(N)_ time.
+    pub fn serialize_compact<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        write_header(w, SerializedKind::Louds, 0)?;
+        self.lbs.serialize_body(w, false)
+    }
+
+    /// Deserializes what [serialize()](#method.serialize) or [serialize_compact()](#method.serialize_compact) wrote.
+    ///
+    /// The header tells whether the rank/select directory is present; it is rebuilt when absent.
+    /// The select samples, the level boundaries and the leaf directory of `Louds` are not serialized, so they are always rebuilt in _O(N)_ time.
+    ///
+    /// # Errors
+    /// When `r` cannot be read, or its content is not a serialized `Louds`.
+    pub fn deserialize<R: Read>(r: &mut R) -> io::Result<Self> {
+        let flags = read_header(r, SerializedKind::Louds)?;
+        let lbs = SuccinctBitVector::deserialize_body(r, flags & FLAG_DIRECTORY != 0)?;
+        LoudsBuilder::check_lbs((0..lbs.length()).map(|i| lbs.access(i))).map_err(invalid_data)?;
+        Ok(Louds::from_lbs(lbs))
+    }
+}
@@ -0,0 +1,265 @@
+use super::{Louds, LoudsBuilder, LoudsDescendants, LoudsNodeNum};
+use std::borrow::Borrow;
+
+impl Louds {
+    /// Cuts out the subtree rooted at `node_num` as a new `Louds`.
+    ///
+    /// Returns the new tree and the mapping from its NodeNums to the original ones: `original[k - 1]` is the original NodeNum of new node#k.
+    ///
+    /// The descendants of `node_num` at each depth have consecutive NodeNums, so it walks the subtree level by level and takes _O(M)_ time where _M_ is the size of the subtree.
+    ///
+    /// # Examples
+    /// ```
+    /// use succinct_rs::{BitString, LoudsBuilder, LoudsNodeNum};
+    ///
+    /// let louds = LoudsBuilder::from_bit_string(BitString::new("10_1110_10_0_1110_0_0_10_110_0_0_0")).build();
+    /// let (subtree, original) = louds.subtree(LoudsNodeNum::new(4));
+    ///
+    /// assert_eq!(format!("{}", subtree), "10_1110_0_10_110_0_0_0 (nodes: 7)");
+    /// assert_eq!(original.iter().map(|n| n.value()).collect::<Vec<u64>>(), vec!(4, 6, 7, 8, 9, 10, 11));
+    /// ```
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this LOUDS.
+    pub fn subtree(&self, node_num: impl Borrow<LoudsNodeNum>) -> (Louds, Vec<LoudsNodeNum>) {
+        let node_num = node_num.borrow();
+        self.validate_node_num(node_num);
+
+        let mut degrees = vec![];
+        let mut original = vec![];
+
+        // NodeNum range [start, end) of the subtree's nodes at the current depth.
+        let (mut start, mut end) = (node_num.value(), node_num.value() + 1);
+        while start < end {
+            for n in start..end {
+                let n = LoudsNodeNum::new(n);
+                degrees.push(self.degree(n) as usize);
+                original.push(n);
+            }
+            let (next_start, next_end) = self.next_level_node_nums(start, end);
+            start = next_start;
+            end = next_end;
+        }
+
+        (LoudsBuilder::from_degrees(degrees).build(), original)
+    }
+
+    /// Returns the number of nodes in the subtree rooted at `node_num`, including `node_num` itself.
+    ///
+    /// It walks the leftmost and rightmost descendant chains level by level: the descendants at each depth are the NodeNums between them.
+    /// So it takes _O(h)_ select and rank operations where _h_ is the height of the subtree.
+    ///
+    /// # Examples
+    /// ```
+    /// use succinct_rs::{BitString, LoudsBuilder, LoudsNodeNum};
+    ///
+    /// let louds = LoudsBuilder::from_bit_string(BitString::new("10_1110_10_0_1110_0_0_10_110_0_0_0")).build();
+    ///
+    /// assert_eq!(louds.subtree_size(LoudsNodeNum::new(1)), 11);
+    /// assert_eq!(louds.subtree_size(LoudsNodeNum::new(4)), 7);
+    /// assert_eq!(louds.subtree_size(LoudsNodeNum::new(3)), 1);
+    /// ```
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this LOUDS.
+    pub fn subtree_size(&self, node_num: impl Borrow<LoudsNodeNum>) -> u64 {
+        let node_num = node_num.borrow();
+        self.validate_node_num(node_num);
+
+        let (mut start, mut end) = (node_num.value(), node_num.value() + 1);
+        let mut size = 0;
+        while start < end {
+            size += end - start;
+            let (next_start, next_end) = self.next_level_node_nums(start, end);
+            start = next_start;
+            end = next_end;
+        }
+        size
+    }
+
+    /// Returns an iterator over the descendants of `node_num` (excluding `node_num` itself) in level order.
+    ///
+    /// Like [subtree_size()](#method.subtree_size), it moves to the next depth by the leftmost and rightmost descendants, taking _O(1)_ select and rank operations per depth.
+    ///
+    /// # Examples
+    /// ```
+    /// use succinct_rs::{BitString, LoudsBuilder, LoudsNodeNum};
+    ///
+    /// let louds = LoudsBuilder::from_bit_string(BitString::new("10_1110_10_0_1110_0_0_10_110_0_0_0")).build();
+    ///
+    /// let descendants: Vec<u64> = louds.descendants(LoudsNodeNum::new(4)).map(|n| n.value()).collect();
+    /// assert_eq!(descendants, vec!(6, 7, 8, 9, 10, 11));
+    /// assert_eq!(louds.descendants(LoudsNodeNum::new(3)).next(), None);
+    /// ```
+    ///
+    /// # Panics
+    /// `node_num` does not exist in this LOUDS.
+    pub fn descendants(&self, node_num: impl Borrow<LoudsNodeNum>) -> LoudsDescendants<'_> {
+        let node_num = node_num.borrow();
+        self.validate_node_num(node_num);
+        LoudsDescendants::new(self, node_num.value())
+    }
+
+    /// Returns the NodeNum range _[start, end)_ of the children of node#`start` ..= node#_(`end` - 1)_, which is the next level of a subtree's level _[start, end)_.
+    ///
+    /// The range starts from the first child position of node#`start` and ends at that of node#_(`end` - 1)_, even if they are leaves.
+    pub(super) fn next_level_node_nums(&self, start: u64, end: u64) -> (u64, u64) {
+        let (next_start, _) = self.children_node_nums(start);
+        let (_, next_end) = self.children_node_nums(end - 1);
+        (next_start, next_end)
+    }
+}
+
+#[cfg(test)]
+mod subtree_success_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, node_num, expected_louds, expected_original): (&str, u64, &str, Vec<u64>) = $value;
+                let louds = LoudsBuilder::from_bit_string(BitString::new(in_s)).build();
+                let (subtree, original) = louds.subtree(LoudsNodeNum::new(node_num));
+                assert_eq!(format!("{}", subtree), expected_louds);
+                assert_eq!(original.iter().map(|n| n.value()).collect::<Vec<u64>>(), expected_original);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", 1, "10_0 (nodes: 1)", vec!(1)),
+
+        t2_1: ("10_10_0", 1, "10_10_0 (nodes: 2)", vec!(1, 2)),
+        t2_2: ("10_10_0", 2, "10_0 (nodes: 1)", vec!(2)),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, "10_1110_10_0_1110_0_0_10_110_0_0_0 (nodes: 11)", vec!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11)),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, "10_10_0 (nodes: 2)", vec!(2, 5)),
+        t3_3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, "10_0 (nodes: 1)", vec!(3)),
+        t3_4: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, "10_1110_0_10_110_0_0_0 (nodes: 7)", vec!(4, 6, 7, 8, 9, 10, 11)),
+        t3_5: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 7, "10_10_0 (nodes: 2)", vec!(7, 9)),
+        t3_6: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 8, "10_110_0_0 (nodes: 3)", vec!(8, 10, 11)),
+        t3_7: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 11, "10_0 (nodes: 1)", vec!(11)),
+    }
+}
+
+#[cfg(test)]
+mod subtree_failure_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_node_not_found_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, node_num) = $value;
+                let louds = LoudsBuilder::from_bit_string(BitString::new(in_s)).build();
+                let _ = louds.subtree(LoudsNodeNum::new(node_num));
+            }
+        )*
+        }
+    }
+
+    parameterized_node_not_found_tests! {
+        t1_1: ("10_0", 0),
+        t1_2: ("10_0", 2),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 0),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 12),
+    }
+}
+
+#[cfg(test)]
+mod subtree_size_descendants_success_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, node_num, expected_descendants): (&str, u64, Vec<u64>) = $value;
+                let louds = LoudsBuilder::from_bit_string(BitString::new(in_s)).build();
+                let node_num = LoudsNodeNum::new(node_num);
+
+                assert_eq!(louds.subtree_size(node_num), expected_descendants.len() as u64 + 1);
+                assert_eq!(louds.descendants(node_num).map(|n| n.value()).collect::<Vec<u64>>(), expected_descendants);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: ("10_0", 1, vec!()),
+
+        t2_1: ("10_10_0", 1, vec!(2)),
+        t2_2: ("10_10_0", 2, vec!()),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 1, vec!(2, 3, 4, 5, 6, 7, 8, 9, 10, 11)),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 2, vec!(5)),
+        t3_3: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 3, vec!()),
+        t3_4: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 4, vec!(6, 7, 8, 9, 10, 11)),
+        t3_5: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 6, vec!()),
+        t3_6: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 7, vec!(9)),
+        t3_7: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 8, vec!(10, 11)),
+        t3_8: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 11, vec!()),
+
+        // Leftmost and rightmost descendants are leaves while inner nodes have children.
+        t4_1: ("10_1110_0_10_0_10_0", 1, vec!(2, 3, 4, 5, 6)),
+        t4_2: ("10_1110_0_10_0_10_0", 3, vec!(5, 6)),
+    }
+}
+
+#[cfg(test)]
+mod subtree_size_failure_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_node_not_found_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, node_num) = $value;
+                let louds = LoudsBuilder::from_bit_string(BitString::new(in_s)).build();
+                let _ = louds.subtree_size(LoudsNodeNum::new(node_num));
+            }
+        )*
+        }
+    }
+
+    parameterized_node_not_found_tests! {
+        t1_1: ("10_0", 0),
+        t1_2: ("10_0", 2),
+
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 0),
+        t3_2: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 12),
+    }
+}
+
+#[cfg(test)]
+mod descendants_failure_tests {
+    use crate::{BitString, LoudsBuilder, LoudsNodeNum};
+
+    macro_rules! parameterized_node_not_found_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (in_s, node_num) = $value;
+                let louds = LoudsBuilder::from_bit_string(BitString::new(in_s)).build();
+                let _ = louds.descendants(LoudsNodeNum::new(node_num));
+            }
+        )*
+        }
+    }
+
+    parameterized_node_not_found_tests! {
+        t1_1: ("10_0", 0),
+        t3_1: ("10_1110_10_0_1110_0_0_10_110_0_0_0", 12),
+    }
+}
@@ -0,0 +1,197 @@
+use super::{Louds, LoudsNodeNum, LoudsTraversal, LoudsTraversalWithDepth, TraversalState};
+
+impl Louds {
+    /// Returns an iterator over all the nodes in breadth-first order, which is NodeNum order in LOUDS.
+    ///
+    /// # Examples
+    /// ```
+    /// use succinct_rs::{BitString, LoudsBuilder, LoudsNodeNum};
+    ///
+    /// let louds = LoudsBuilder::from_bit_string(BitString::new("10_110_10_0_0")).build();
+    /// let nodes: Vec<u64> = louds.bfs().map(|n| n.value()).collect();
+    /// assert_eq!(nodes, vec!(1, 2, 3, 4));
+    ///
+    /// let depths: Vec<u64> = louds.bfs().with_depth().map(|(_, d)| d).collect();
+    /// assert_eq!(depths, vec!(0, 1, 1, 2));
+    /// ```
+    pub fn bfs(&self) -> LoudsTraversal<'_> {
+        LoudsTraversal {
+            louds: self,
+            state: TraversalState::Bfs { next: 1, depth: 0 },
+        }
+    }
+
+    /// Returns an iterator over all the nodes in depth-first pre-order (a parent before its children, children from left to right).
+    ///
+    /// It keeps an explicit stack of _O(H)_ entries where _H_ is the height of the tree, so deep trees do not overflow the call stack.
+    ///
+    /// # Examples
+    /// ```
+    /// use succinct_rs::{BitString, LoudsBuilder};
+    ///
+    /// let louds = LoudsBuilder::from_bit_string(BitString::new("10_110_10_0_0")).build();
+    /// let nodes: Vec<u64> = louds.dfs_preorder().map(|n| n.value()).collect();
+    /// assert_eq!(nodes, vec!(1, 2, 4, 3));
+    /// ```
+    pub fn dfs_preorder(&self) -> LoudsTraversal<'_> {
+        LoudsTraversal {
+            louds: self,
+            state: TraversalState::PreOrder {
+                stack: vec![(1, 2, 0)],
+            },
+        }
+    }
+
+    /// Returns an iterator over all the nodes in depth-first post-order (children from left to right, then their parent).
+    ///
+    /// It keeps an explicit stack of _O(H)_ entries where _H_ is the height of the tree, so deep trees do not overflow the call stack.
+    ///
+    /// # Examples
+    /// ```
+    /// use succinct_rs::{BitString, LoudsBuilder};
+    ///
+    /// let louds = LoudsBuilder::from_bit_string(BitString::new("10_110_10_0_0")).build();
+    /// let nodes: Vec<u64> = louds.dfs_postorder().map(|n| n.value()).collect();
+    /// assert_eq!(nodes, vec!(4, 2, 3, 1));
+    /// ```
+    pub fn dfs_postorder(&self) -> LoudsTraversal<'_> {
+        let (first_child, end_child) = self.children_node_nums(1);
+        LoudsTraversal {
+            louds: self,
+            state: TraversalState::PostOrder {
+                stack: vec![(1, 0, first_child, end_child)],
+            },
+        }
+    }
+
+    /// Returns the NodeNum range _[first, end)_ of `node_num`'s children.
+    ///
+    /// Children have consecutive NodeNums: the first one is _(the number of '1' before `node_num`'s unary degree) + 1_.
+    pub(super) fn children_node_nums(&self, node_num: u64) -> (u64, u64) {
+        let node_num = LoudsNodeNum::new(node_num);
+        let start = self.children_start(&node_num);
+        let end = self.children_end(&node_num);
+        let first = self.lbs.rank(start - 1) + 1;
+        (first, first + (end - start))
+    }
+}
+
+impl<'a> LoudsTraversal<'a> {
+    /// Makes this iterator yield `(node, depth)` pairs; the root's depth is 0.
+    pub fn with_depth(self) -> LoudsTraversalWithDepth<'a> {
+        LoudsTraversalWithDepth { traversal: self }
+    }
+
+    fn next_with_depth(&mut self) -> Option<(LoudsNodeNum, u64)> {
+        let louds = self.louds;
+        match self.state {
+            TraversalState::Bfs {
+                ref mut next,
+                ref mut depth,
+            } => {
+                let node_num = *next;
+                if node_num > louds.nodes_cnt() {
+                    return None;
+                }
+                while louds.level_starts[*depth as usize + 1] <= node_num {
+                    *depth += 1;
+                }
+                *next += 1;
+                Some((LoudsNodeNum::new(node_num), *depth))
+            }
+            TraversalState::PreOrder { ref mut stack } => {
+                let top = stack.last_mut()?;
+                let (node_num, depth) = (top.0, top.2);
+                top.0 += 1;
+                if top.0 == top.1 {
+                    stack.pop();
+                }
+
+                let (first_child, end_child) = louds.children_node_nums(node_num);
+                if first_child < end_child {
+                    stack.push((first_child, end_child, depth + 1));
+                }
+                Some((LoudsNodeNum::new(node_num), depth))
+            }
+            TraversalState::PostOrder { ref mut stack } => loop {
+                let child = {
+                    let top = stack.last_mut()?;
+                    if top.2 < top.3 {
+                        top.2 += 1;
+                        Some((top.2 - 1, top.1 + 1))
+                    } else {
+                        None
+                    }
+                };
+                match child {
+                    Some((child, child_depth)) => {
+                        let (first_child, end_child) = louds.children_node_nums(child);
+                        stack.push((child, child_depth, first_child, end_child));
+                    }
+                    None => {
+                        let (node_num, depth, _, _) = stack.pop().unwrap();
+                        return Some((LoudsNodeNum::new(node_num), depth));
+                    }
+                }
+            },
+        }
+    }
+}
+
+impl<'a> Iterator for LoudsTraversal<'a> {
+    type Item = LoudsNodeNum;
+
+    fn next(&mut self) -> Option<LoudsNodeNum> {
+        self.next_with_depth().map(|(node_num, _)| node_num)
+    }
+}
+
+impl<'a> Iterator for LoudsTraversalWithDepth<'a> {
+    type Item = (LoudsNodeNum, u64);
+
+    fn next(&mut self) -> Option<(LoudsNodeNum, u64)> {
+        self.traversal.next_with_depth()
+    }
+}
+
+#[cfg(test)]
+mod traversal_success_tests {
+    use crate::{BitString, LoudsBuilder};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_s, expected_bfs, expected_preorder, expected_postorder): (&str, Vec<(u64, u64)>, Vec<(u64, u64)>, Vec<(u64, u64)>) = $value;
+                let bs = BitString::new(in_s);
+                let louds = LoudsBuilder::from_bit_string(bs).build();
+
+                let bfs: Vec<(u64, u64)> = louds.bfs().with_depth().map(|(n, d)| (n.value(), d)).collect();
+                assert_eq!(bfs, expected_bfs);
+                let preorder: Vec<(u64, u64)> = louds.dfs_preorder().with_depth().map(|(n, d)| (n.value(), d)).collect();
+                assert_eq!(preorder, expected_preorder);
+                let postorder: Vec<(u64, u64)> = louds.dfs_postorder().with_depth().map(|(n, d)| (n.value(), d)).collect();
+                assert_eq!(postorder, expected_postorder);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: ("10_0", vec!((1, 0)), vec!((1, 0)), vec!((1, 0))),
+        t2: ("10_10_0", vec!((1, 0), (2, 1)), vec!((1, 0), (2, 1)), vec!((2, 1), (1, 0))),
+        t3: (
+            "10_1110_10_0_1110_0_0_10_110_0_0_0",
+            vec!((1, 0), (2, 1), (3, 1), (4, 1), (5, 2), (6, 2), (7, 2), (8, 2), (9, 3), (10, 3), (11, 3)),
+            vec!((1, 0), (2, 1), (5, 2), (3, 1), (4, 1), (6, 2), (7, 2), (9, 3), (8, 2), (10, 3), (11, 3)),
+            vec!((5, 2), (2, 1), (3, 1), (6, 2), (9, 3), (7, 2), (10, 3), (11, 3), (8, 2), (4, 1), (1, 0))
+        ),
+        t4: (
+            "10_10_10_10_0",
+            vec!((1, 0), (2, 1), (3, 2), (4, 3)),
+            vec!((1, 0), (2, 1), (3, 2), (4, 3)),
+            vec!((4, 3), (3, 2), (2, 1), (1, 0))
+        ),
+    }
+}
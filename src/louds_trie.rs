@@ -0,0 +1,53 @@
+#[allow(clippy::module_inception)]
+mod louds_trie;
+mod louds_trie_builder;
+
+use crate::{LabeledLouds, SuccinctBitVector};
+
+/// Static [trie](https://en.wikipedia.org/wiki/Trie) of byte-string keys on top of [LOUDS](../louds/struct.Louds.html).
+///
+/// Each edge's byte is stored as the label of its child node ([LabeledLouds](../louds/struct.LabeledLouds.html)), and nodes where keys end are marked in a [SuccinctBitVector](../succinct_bit_vector/struct.SuccinctBitVector.html).
+///
+/// Keys get ids _0, 1, ..., (number of keys) - 1_ in the level order of their last nodes, i.e. shorter keys get smaller ids.
+///
+/// # Examples
+/// ```
+/// extern crate succinct_rs;
+///
+/// use succinct_rs::LoudsTrieBuilder;
+///
+/// let trie = LoudsTrieBuilder::new()
+///     .add("an")
+///     .add("and")
+///     .add("ant")
+///     .add("bee")
+///     .build();
+///
+/// assert!(trie.contains("and"));
+/// assert!(!trie.contains("a"));
+///
+/// // key <-> id
+/// let id = trie.get("ant").unwrap();
+/// assert_eq!(trie.key(id), b"ant".to_vec());
+///
+/// // Keys which are prefixes of "answer".
+/// assert_eq!(trie.prefix_search("answer"), vec!(b"an".to_vec()));
+///
+/// // Keys which start with "an", in lexicographic order.
+/// assert_eq!(
+///     trie.predictive_search("an"),
+///     vec!(b"an".to_vec(), b"and".to_vec(), b"ant".to_vec())
+/// );
+/// ```
+pub struct LoudsTrie {
+    /// Tree with each node labeled by the byte of the edge from its parent. The root's label is meaningless.
+    louds: LabeledLouds<u8>,
+
+    /// _(k - 1)_-th bit is '1' when a key ends at node#k.
+    terminals: SuccinctBitVector,
+}
+
+/// The builder of [LoudsTrie](struct.LoudsTrie.html).
+pub struct LoudsTrieBuilder {
+    keys: Vec<Vec<u8>>,
+}
@@ -0,0 +1,277 @@
+use super::LoudsTrie;
+use crate::{LabeledLouds, LoudsNodeNum};
+
+impl LoudsTrie {
+    /// Returns the number of keys.
+    pub fn len(&self) -> u64 {
+        let terminals = &self.terminals;
+        terminals.rank(terminals.length() - 1)
+    }
+
+    /// Returns whether this trie has no key.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the underlying tree, whose nodes are labeled by the bytes of edges.
+    pub fn louds(&self) -> &LabeledLouds<u8> {
+        &self.louds
+    }
+
+    /// Returns whether `key` is in this trie.
+    pub fn contains<K: AsRef<[u8]>>(&self, key: K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns the id of `key` if exists.
+    ///
+    /// It takes _O(L log σ)_ time where _L_ is the length of `key` and _σ_ is the alphabet size.
+    pub fn get<K: AsRef<[u8]>>(&self, key: K) -> Option<u64> {
+        let node_num = self.find(key.as_ref())?;
+        if self.is_terminal(&node_num) {
+            Some(self.terminals.rank(node_num.value() - 1) - 1)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the key of `id`.
+    ///
+    /// It climbs from the key's last node to the root with [Louds::child_to_parent()](../louds/struct.Louds.html#method.child_to_parent).
+    ///
+    /// # Panics
+    /// When _`id` >= `self.len()`_.
+    pub fn key(&self, id: u64) -> Vec<u8> {
+        let node = self.terminals.select(id + 1).unwrap_or_else(|| {
+            panic!(
+                "id = {} must be smaller than {} (self.len())",
+                id,
+                self.len()
+            )
+        });
+        let louds = self.louds.louds();
+
+        let mut key = vec![];
+        let mut node_num = LoudsNodeNum::new(node + 1);
+        while node_num.value() != 1 {
+            key.push(*self.louds.label(node_num));
+            node_num = louds.child_to_parent(louds.node_num_to_index(node_num));
+        }
+        key.reverse();
+        key
+    }
+
+    /// Returns the keys which are prefixes of `query` (including `query` itself), from shorter to longer.
+    pub fn prefix_search<K: AsRef<[u8]>>(&self, query: K) -> Vec<Vec<u8>> {
+        let query = query.as_ref();
+        let mut keys = vec![];
+
+        let mut node_num = LoudsNodeNum::new(1);
+        for depth in 0..=query.len() {
+            if self.is_terminal(&node_num) {
+                keys.push(query[..depth].to_vec());
+            }
+            if depth == query.len() {
+                break;
+            }
+            match self.louds.child_by_label(node_num, &query[depth]) {
+                Some(child) => node_num = child,
+                None => break,
+            }
+        }
+        keys
+    }
+
+    /// Returns the keys which start with `prefix` (including `prefix` itself), in lexicographic order.
+    ///
+    /// It walks the subtree under `prefix` in depth-first pre-order with an explicit stack.
+    pub fn predictive_search<K: AsRef<[u8]>>(&self, prefix: K) -> Vec<Vec<u8>> {
+        let prefix = prefix.as_ref();
+        let mut keys = vec![];
+        let start = match self.find(prefix) {
+            Some(node_num) => node_num,
+            None => return keys,
+        };
+        let louds = self.louds.louds();
+
+        // Children are sorted by their labels, so pre-order visits keys in lexicographic order.
+        let mut stack = vec![(start, prefix.to_vec())];
+        while let Some((node_num, key)) = stack.pop() {
+            if self.is_terminal(&node_num) {
+                keys.push(key.clone());
+            }
+            let children: Vec<LoudsNodeNum> = louds
+                .children(node_num)
+                .map(|index| louds.index_to_node_num(index))
+                .collect();
+            for child in children.into_iter().rev() {
+                let mut child_key = key.clone();
+                child_key.push(*self.louds.label(child));
+                stack.push((child, child_key));
+            }
+        }
+        keys
+    }
+
+    /// Returns the node reached by following `key` from the root.
+    fn find(&self, key: &[u8]) -> Option<LoudsNodeNum> {
+        let mut node_num = LoudsNodeNum::new(1);
+        for byte in key {
+            node_num = self.louds.child_by_label(node_num, byte)?;
+        }
+        Some(node_num)
+    }
+
+    fn is_terminal(&self, node_num: &LoudsNodeNum) -> bool {
+        self.terminals.access(node_num.value() - 1)
+    }
+}
+
+#[cfg(test)]
+mod get_success_tests {
+    use crate::LoudsTrieBuilder;
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (keys, query, expected_id): (Vec<&str>, &str, Option<u64>) = $value;
+                let mut builder = LoudsTrieBuilder::new();
+                for key in keys {
+                    builder.add(key);
+                }
+                let trie = builder.build();
+                assert_eq!(trie.get(query), expected_id);
+                assert_eq!(trie.contains(query), expected_id.is_some());
+                if let Some(id) = expected_id {
+                    assert_eq!(trie.key(id), query.as_bytes().to_vec());
+                }
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: (vec!(), "", None),
+        t1_2: (vec!(), "a", None),
+
+        t2_1: (vec!(""), "", Some(0)),
+        t2_2: (vec!(""), "a", None),
+
+        t3_1: (vec!("a"), "", None),
+        t3_2: (vec!("a"), "a", Some(0)),
+        t3_3: (vec!("a"), "ab", None),
+
+        // Ids are in level order of the keys' last nodes: an, and, ant, bee, bear.
+        t4_1: (vec!("bear", "ant", "an", "bee", "and", "an"), "an", Some(0)),
+        t4_2: (vec!("bear", "ant", "an", "bee", "and", "an"), "bee", Some(3)),
+        t4_3: (vec!("bear", "ant", "an", "bee", "and", "an"), "and", Some(1)),
+        t4_4: (vec!("bear", "ant", "an", "bee", "and", "an"), "ant", Some(2)),
+        t4_5: (vec!("bear", "ant", "an", "bee", "and", "an"), "bear", Some(4)),
+        t4_6: (vec!("bear", "ant", "an", "bee", "and", "an"), "", None),
+        t4_7: (vec!("bear", "ant", "an", "bee", "and", "an"), "a", None),
+        t4_8: (vec!("bear", "ant", "an", "bee", "and", "an"), "be", None),
+        t4_9: (vec!("bear", "ant", "an", "bee", "and", "an"), "bees", None),
+        t4_10: (vec!("bear", "ant", "an", "bee", "and", "an"), "cat", None),
+    }
+}
+
+#[cfg(test)]
+mod key_failure_tests {
+    use crate::LoudsTrieBuilder;
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            #[should_panic]
+            fn $name() {
+                let (keys, id): (Vec<&str>, u64) = $value;
+                let mut builder = LoudsTrieBuilder::new();
+                for key in keys {
+                    builder.add(key);
+                }
+                let _ = builder.build().key(id);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: (vec!(), 0),
+        t2: (vec!("a"), 1),
+        t3: (vec!("a", "b", "a"), 2),
+    }
+}
+
+#[cfg(test)]
+mod prefix_search_success_tests {
+    use crate::LoudsTrieBuilder;
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (keys, query, expected_keys): (Vec<&str>, &str, Vec<&str>) = $value;
+                let mut builder = LoudsTrieBuilder::new();
+                for key in keys {
+                    builder.add(key);
+                }
+                let trie = builder.build();
+                let expected_keys: Vec<Vec<u8>> = expected_keys.iter().map(|k| k.as_bytes().to_vec()).collect();
+                assert_eq!(trie.prefix_search(query), expected_keys);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: (vec!(), "", vec!()),
+        t1_2: (vec!(), "abc", vec!()),
+
+        t2_1: (vec!("", "a", "abc", "abd", "b"), "", vec!("")),
+        t2_2: (vec!("", "a", "abc", "abd", "b"), "a", vec!("", "a")),
+        t2_3: (vec!("", "a", "abc", "abd", "b"), "ab", vec!("", "a")),
+        t2_4: (vec!("", "a", "abc", "abd", "b"), "abc", vec!("", "a", "abc")),
+        t2_5: (vec!("", "a", "abc", "abd", "b"), "abcd", vec!("", "a", "abc")),
+        t2_6: (vec!("", "a", "abc", "abd", "b"), "bc", vec!("", "b")),
+        t2_7: (vec!("", "a", "abc", "abd", "b"), "c", vec!("")),
+    }
+}
+
+#[cfg(test)]
+mod predictive_search_success_tests {
+    use crate::LoudsTrieBuilder;
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (keys, prefix, expected_keys): (Vec<&str>, &str, Vec<&str>) = $value;
+                let mut builder = LoudsTrieBuilder::new();
+                for key in keys {
+                    builder.add(key);
+                }
+                let trie = builder.build();
+                let expected_keys: Vec<Vec<u8>> = expected_keys.iter().map(|k| k.as_bytes().to_vec()).collect();
+                assert_eq!(trie.predictive_search(prefix), expected_keys);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1_1: (vec!(), "", vec!()),
+        t1_2: (vec!(), "abc", vec!()),
+
+        t2_1: (vec!("b", "abd", "", "abc", "a"), "", vec!("", "a", "abc", "abd", "b")),
+        t2_2: (vec!("b", "abd", "", "abc", "a"), "a", vec!("a", "abc", "abd")),
+        t2_3: (vec!("b", "abd", "", "abc", "a"), "ab", vec!("abc", "abd")),
+        t2_4: (vec!("b", "abd", "", "abc", "a"), "abd", vec!("abd")),
+        t2_5: (vec!("b", "abd", "", "abc", "a"), "abcd", vec!()),
+        t2_6: (vec!("b", "abd", "", "abc", "a"), "c", vec!()),
+    }
+}
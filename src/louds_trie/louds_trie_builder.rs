@@ -0,0 +1,69 @@
+use super::{LoudsTrie, LoudsTrieBuilder};
+use crate::{LabeledLouds, LoudsBuilder, SuccinctBitVectorBuilder};
+use std::collections::VecDeque;
+
+impl super::LoudsTrieBuilder {
+    /// Prepares for building an empty [LoudsTrie](struct.LoudsTrie.html).
+    pub fn new() -> Self {
+        Self { keys: vec![] }
+    }
+
+    /// Adds `key`. Keys can be added in any order, and duplicates are ignored.
+    pub fn add<K: AsRef<[u8]>>(&mut self, key: K) -> &mut Self {
+        self.keys.push(key.as_ref().to_vec());
+        self
+    }
+
+    /// Build [LoudsTrie](struct.LoudsTrie.html).
+    ///
+    /// It sorts the keys and takes _O(M log K)_ time where _K_ is the number of keys and _M_ is the total length of them.
+    pub fn build(&self) -> LoudsTrie {
+        let mut keys: Vec<&[u8]> = self.keys.iter().map(|k| &k[..]).collect();
+        keys.sort();
+        keys.dedup();
+
+        // Visit nodes in level order. Each node is the range of sorted keys sharing its prefix of length `depth`.
+        let (mut degrees, mut labels, mut terminals) = (vec![], vec![0u8], vec![]);
+        let mut queue = VecDeque::new();
+        queue.push_back((0, keys.len(), 0));
+        while let Some((lo, hi, depth)) = queue.pop_front() {
+            // The key equal to the prefix, if any, comes first.
+            let is_terminal = lo < hi && keys[lo].len() == depth;
+            terminals.push(is_terminal);
+
+            let mut degree = 0;
+            let mut i = if is_terminal { lo + 1 } else { lo };
+            while i < hi {
+                let byte = keys[i][depth];
+                let mut j = i + 1;
+                while j < hi && keys[j][depth] == byte {
+                    j += 1;
+                }
+                labels.push(byte);
+                queue.push_back((i, j, depth + 1));
+                degree += 1;
+                i = j;
+            }
+            degrees.push(degree);
+        }
+
+        let louds = LoudsBuilder::from_degrees(degrees).build();
+        let mut terminals_builder = SuccinctBitVectorBuilder::from_length(terminals.len() as u64);
+        for (i, is_terminal) in terminals.iter().enumerate() {
+            if *is_terminal {
+                terminals_builder.set_bit(i as u64);
+            }
+        }
+
+        LoudsTrie {
+            louds: LabeledLouds::new(louds, labels),
+            terminals: terminals_builder.build(),
+        }
+    }
+}
+
+impl Default for LoudsTrieBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
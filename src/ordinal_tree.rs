@@ -0,0 +1,119 @@
+mod ordinal_children;
+mod ordinal_tree_algorithms;
+
+pub use ordinal_tree_algorithms::{serialize, to_louds};
+
+/// Ordinal tree: a rooted tree whose children are ordered.
+///
+/// Algorithms written against this trait work on any tree encoding, e.g. [Louds](../louds/struct.Louds.html) or a plain pointer-based tree.
+///
+/// Implementors provide navigation by parent, first child, next sibling and children.
+/// [OrdinalChildren](struct.OrdinalChildren.html) implements children by first child and next sibling, for encodings without a faster way.
+/// The other methods are derived from them, and can be overridden when an encoding answers them faster.
+///
+/// Method names differ from the inherent ones of [Louds](../louds/struct.Louds.html), which take and return [LoudsIndex](../louds/struct.LoudsIndex.html) in some places.
+///
+/// # Examples
+/// ```
+/// use succinct_rs::{BitString, LoudsBuilder, LoudsNodeNum, OrdinalTree};
+///
+/// fn leaves<T: OrdinalTree>(tree: &T) -> Vec<T::Node> {
+///     tree.level_order().into_iter().filter(|&n| tree.is_leaf_node(n)).collect()
+/// }
+///
+/// let louds = LoudsBuilder::from_bit_string(BitString::new("10_1110_10_0_1110_0_0_10_110_0_0_0")).build();
+/// assert_eq!(
+///     leaves(&louds),
+///     vec!(3, 5, 6, 9, 10, 11).into_iter().map(LoudsNodeNum::new).collect::<Vec<LoudsNodeNum>>()
+/// );
+/// assert_eq!(louds.lowest_common_ancestor(LoudsNodeNum::new(9), LoudsNodeNum::new(11)), LoudsNodeNum::new(4));
+/// ```
+pub trait OrdinalTree {
+    /// Handle of a node.
+    type Node: Copy + Eq;
+
+    /// Iterator over the children of a node, returned by [child_nodes()](#tymethod.child_nodes).
+    type Children<'a>: Iterator<Item = Self::Node>
+    where
+        Self: 'a;
+
+    /// Returns the root.
+    fn root(&self) -> Self::Node;
+
+    /// Returns the parent of `node`, or None for the root.
+    fn parent_node(&self, node: Self::Node) -> Option<Self::Node>;
+
+    /// Returns the leftmost child of `node` if exists.
+    fn first_child_node(&self, node: Self::Node) -> Option<Self::Node>;
+
+    /// Returns the next (right) sibling of `node` if exists.
+    fn next_sibling_node(&self, node: Self::Node) -> Option<Self::Node>;
+
+    /// Returns an iterator over the children of `node`, from left to right.
+    fn child_nodes(&self, node: Self::Node) -> Self::Children<'_>;
+
+    /// Returns the number of nodes.
+    fn nodes_cnt(&self) -> u64;
+
+    /// Returns the number of children of `node`.
+    fn children_cnt(&self, node: Self::Node) -> u64 {
+        self.child_nodes(node).count() as u64
+    }
+
+    /// Returns whether `node` has no child.
+    fn is_leaf_node(&self, node: Self::Node) -> bool {
+        self.first_child_node(node).is_none()
+    }
+
+    /// Returns the depth of `node`; the root's depth is 0.
+    ///
+    /// By default, it follows parents and takes _O(depth)_ parent queries.
+    fn node_depth(&self, node: Self::Node) -> u64
+    where
+        Self: Sized,
+    {
+        ordinal_tree_algorithms::depth(self, node)
+    }
+
+    /// Returns the lowest common ancestor of `a` and `b`.
+    ///
+    /// By default, it lifts the deeper node to the other's depth, then lifts both until they meet, taking _O(depth)_ parent queries.
+    fn lowest_common_ancestor(&self, a: Self::Node, b: Self::Node) -> Self::Node
+    where
+        Self: Sized,
+    {
+        ordinal_tree_algorithms::lca(self, a, b)
+    }
+
+    /// Returns all the nodes in breadth-first (level) order.
+    fn level_order(&self) -> Vec<Self::Node>
+    where
+        Self: Sized,
+    {
+        ordinal_tree_algorithms::bfs(self)
+    }
+
+    /// Returns all the nodes in depth-first preorder (parents before children), without recursion.
+    fn preorder(&self) -> Vec<Self::Node>
+    where
+        Self: Sized,
+    {
+        ordinal_tree_algorithms::dfs_preorder(self)
+    }
+
+    /// Returns all the nodes in depth-first postorder (children before parents), without recursion.
+    fn postorder(&self) -> Vec<Self::Node>
+    where
+        Self: Sized,
+    {
+        ordinal_tree_algorithms::dfs_postorder(self)
+    }
+}
+
+/// Iterator over the children of an [OrdinalTree](trait.OrdinalTree.html) node by its first child and next siblings.
+///
+/// Implementors without a faster way can use it as [OrdinalTree::Children](trait.OrdinalTree.html#associatedtype.Children).
+pub struct OrdinalChildren<'a, T: OrdinalTree> {
+    tree: &'a T,
+    next: Option<T::Node>,
+}
@@ -0,0 +1,18 @@
+use super::{OrdinalChildren, OrdinalTree};
+
+impl<'a, T: OrdinalTree> OrdinalChildren<'a, T> {
+    /// Constructor. `first` is the leftmost child.
+    pub fn new(tree: &'a T, first: Option<T::Node>) -> Self {
+        Self { tree, next: first }
+    }
+}
+
+impl<'a, T: OrdinalTree> Iterator for OrdinalChildren<'a, T> {
+    type Item = T::Node;
+
+    fn next(&mut self) -> Option<T::Node> {
+        let node = self.next?;
+        self.next = self.tree.next_sibling_node(node);
+        Some(node)
+    }
+}
@@ -0,0 +1,104 @@
+use super::OrdinalTree;
+use crate::{Louds, LoudsBuilder};
+use std::io::{self, Write};
+
+/// Returns the depth of `node`; the root's depth is 0.
+///
+/// It follows parents and takes _O(depth)_ parent queries.
+pub(super) fn depth<T: OrdinalTree>(tree: &T, node: T::Node) -> u64 {
+    let mut d = 0;
+    let mut x = node;
+    while let Some(parent) = tree.parent_node(x) {
+        x = parent;
+        d += 1;
+    }
+    d
+}
+
+/// Returns the lowest common ancestor of `a` and `b`.
+///
+/// It lifts the deeper node to the other's depth, then lifts both until they meet, taking _O(depth)_ parent queries.
+pub(super) fn lca<T: OrdinalTree>(tree: &T, a: T::Node, b: T::Node) -> T::Node {
+    let (depth_a, depth_b) = (tree.node_depth(a), tree.node_depth(b));
+    let (mut deeper, mut shallower, diff) = if depth_a >= depth_b {
+        (a, b, depth_a - depth_b)
+    } else {
+        (b, a, depth_b - depth_a)
+    };
+    for _ in 0..diff {
+        deeper = tree.parent_node(deeper).unwrap();
+    }
+    while deeper != shallower {
+        deeper = tree.parent_node(deeper).unwrap();
+        shallower = tree.parent_node(shallower).unwrap();
+    }
+    deeper
+}
+
+/// Returns all the nodes in breadth-first (level) order.
+pub(super) fn bfs<T: OrdinalTree>(tree: &T) -> Vec<T::Node> {
+    // Queue of BFS, which never shrinks.
+    let mut order = vec![tree.root()];
+    let mut i = 0;
+    while i < order.len() {
+        order.extend(tree.child_nodes(order[i]));
+        i += 1;
+    }
+    order
+}
+
+/// Returns all the nodes in depth-first preorder (parents before children), without recursion.
+pub(super) fn dfs_preorder<T: OrdinalTree>(tree: &T) -> Vec<T::Node> {
+    let mut order = vec![];
+    let mut stack = vec![tree.root()];
+    while let Some(node) = stack.pop() {
+        order.push(node);
+        let top = stack.len();
+        stack.extend(tree.child_nodes(node));
+        // Leftmost child on the top.
+        stack[top..].reverse();
+    }
+    order
+}
+
+/// Returns all the nodes in depth-first postorder (children before parents), without recursion.
+pub(super) fn dfs_postorder<T: OrdinalTree>(tree: &T) -> Vec<T::Node> {
+    let mut order = vec![];
+    // Each entry is a node on the current path and its next child to visit.
+    let mut stack = vec![(tree.root(), tree.first_child_node(tree.root()))];
+    while let Some(top) = stack.last_mut() {
+        match top.1 {
+            Some(child) => {
+                top.1 = tree.next_sibling_node(child);
+                stack.push((child, tree.first_child_node(child)));
+            }
+            None => {
+                order.push(top.0);
+                stack.pop();
+            }
+        }
+    }
+    order
+}
+
+/// Encodes `tree` as [Louds](../louds/struct.Louds.html).
+///
+/// Returns the LOUDS and the mapping from its NodeNums to the nodes of `tree`: `nodes[k - 1]` is the node of node#k.
+pub fn to_louds<T: OrdinalTree>(tree: &T) -> (Louds, Vec<T::Node>) {
+    let nodes = tree.level_order();
+    let louds =
+        LoudsBuilder::from_degrees(nodes.iter().map(|&n| tree.children_cnt(n) as usize)).build();
+    (louds, nodes)
+}
+
+/// Serializes `tree` as [Louds](../louds/struct.Louds.html) with [Louds::serialize()](../louds/struct.Louds.html#method.serialize), so that [Louds::deserialize()](../louds/struct.Louds.html#method.deserialize) reads it back.
+///
+/// Returns the mapping from the NodeNums of the serialized LOUDS to the nodes of `tree`, as [to_louds()](fn.to_louds.html) does.
+///
+/// # Errors
+/// When `w` cannot be written.
+pub fn serialize<T: OrdinalTree, W: Write>(tree: &T, w: &mut W) -> io::Result<Vec<T::Node>> {
+    let (louds, nodes) = to_louds(tree);
+    louds.serialize(w)?;
+    Ok(nodes)
+}
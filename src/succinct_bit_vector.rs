@@ -1,7 +1,12 @@
+#[cfg(feature = "arrow")]
+mod arrow;
 mod block;
 mod blocks;
 mod chunk;
 mod chunks;
+mod sdsl;
+mod serialization;
+#[allow(clippy::module_inception)]
 mod succinct_bit_vector;
 mod succinct_bit_vector_builder;
 
@@ -9,7 +14,9 @@ use super::bit_string::BitString;
 use super::internal_data_structure::popcount_table::PopcountTable;
 use super::internal_data_structure::raw_bit_vector::RawBitVector;
 use std::collections::HashSet;
+use std::sync::Arc;
 
+#[derive(Clone)]
 /// Succinct bit vector.
 ///
 /// This class can handle bit sequence of virtually **arbitrary length.**
@@ -61,6 +68,29 @@ use std::collections::HashSet;
 /// assert_eq!(bv.select0(4), None);    // There is no i where range [0, i] has 4 '0's
 /// ```
 ///
+/// # Storage
+/// Raw bits are held by any storage `S` viewable as `&[u64]`: `Vec<u64>` (default), `Box<[u64]>`, `Arc<[u64]>`, `&[u64]` (e.g. a memory map), or your own type placed in a custom allocator.
+///
+/// ```
+/// extern crate succinct_rs;
+///
+/// use std::sync::Arc;
+/// use succinct_rs::{SuccinctBitVector, SuccinctBitVectorBuilder, BitString};
+///
+/// // `01001`: i-th bit is the (i % 64)-th least significant bit of (i / 64)-th word.
+/// let words: &[u64] = &[0b10010];
+/// let bv = SuccinctBitVector::from_storage(words, 5);
+/// assert_eq!(bv.rank(4), 2);
+///
+/// // Clones share the raw bits, and always share the rank index.
+/// let bv: SuccinctBitVector<Arc<[u64]>> = SuccinctBitVectorBuilder::from_bit_string(BitString::new("01001"))
+///     .build()
+///     .map_storage(Arc::from);
+/// let cloned = bv.clone();
+/// assert!(Arc::ptr_eq(bv.storage(), cloned.storage()));
+/// assert_eq!(cloned.select(2), Some(4));
+/// ```
+///
 /// # Complexity
 /// See [README](https://github.com/laysakura/succinct.rs/blob/master/README.md#succinct-bit-vector-complexity).
 ///
@@ -144,18 +174,20 @@ use std::collections::HashSet;
 /// In summary:
 ///
 ///   _rank() = (value of left chunk) + (value of left block) + (value of table keyed by inner block bits)_.
-pub struct SuccinctBitVector {
+pub struct SuccinctBitVector<S = Vec<u64>> {
     /// Raw data.
-    rbv: RawBitVector,
+    rbv: RawBitVector<S>,
 
     /// Total popcount of _[0, <u>last bit of the chunk</u>]_.
     ///
     /// Each chunk takes _2^64_ at max (when every bit is '1' for bit vector of length of _2^64_).
     /// A chunk has blocks.
-    chunks: Chunks,
+    ///
+    /// Shared between clones, as well as `table`, so that cloning over shared storage does not copy the index.
+    chunks: Arc<Chunks>,
 
     /// Table to calculate inner-block `rank()` in _O(1)_.
-    table: PopcountTable,
+    table: Arc<PopcountTable>,
 }
 
 /// Builder of [SuccinctBitVector](struct.SuccinctBitVector.html).
@@ -167,6 +199,7 @@ pub struct SuccinctBitVectorBuilder {
 enum SuccinctBitVectorSeed {
     Length(u64),
     BitStr(BitString),
+    Raw(RawBitVector),
 }
 
 /// Collection of Chunk.
@@ -181,8 +214,6 @@ struct Chunks {
 struct Chunk {
     value: u64, // popcount
     blocks: Blocks,
-
-    #[allow(dead_code)]
     length: u16,
 }
 
@@ -0,0 +1,72 @@
+use super::SuccinctBitVector;
+use arrow_buffer::{BooleanBuffer, Buffer, NullBuffer};
+
+impl SuccinctBitVector {
+    /// Builds a `SuccinctBitVector` from Arrow's packed booleans (LSB-first), respecting `buffer`'s offset and length.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate arrow_buffer;
+    /// extern crate succinct_rs;
+    ///
+    /// use arrow_buffer::BooleanBuffer;
+    /// use succinct_rs::SuccinctBitVector;
+    ///
+    /// let buffer = BooleanBuffer::from(vec![true, false, false, true, true]).slice(1, 4);
+    /// let bv = SuccinctBitVector::from_arrow_boolean_buffer(&buffer);
+    /// assert_eq!(bv.rank(3), 2); // 0011
+    /// ```
+    ///
+    /// # Panics
+    /// When `buffer` is empty.
+    pub fn from_arrow_boolean_buffer(buffer: &BooleanBuffer) -> Self {
+        assert!(!buffer.is_empty(), "length must be > 0.");
+
+        let words = buffer.bit_chunks().iter_padded().collect::<Vec<u64>>();
+        SuccinctBitVector::from_storage(words, buffer.len() as u64)
+    }
+
+    /// Builds a `SuccinctBitVector` from Arrow's validity bitmap: _i_-th bit is '1' when _i_-th row is non-null.
+    ///
+    /// Then [select(k)](#method.select) is the index of the _k_-th non-null row.
+    ///
+    /// # Examples
+    /// ```
+    /// extern crate arrow_buffer;
+    /// extern crate succinct_rs;
+    ///
+    /// use arrow_buffer::NullBuffer;
+    /// use succinct_rs::SuccinctBitVector;
+    ///
+    /// let nulls = NullBuffer::from(vec![false, true, false, true, true]);
+    /// let bv = SuccinctBitVector::from_arrow_null_buffer(&nulls);
+    /// assert_eq!(bv.select(2), Some(3)); // 2nd non-null row is row#3
+    /// ```
+    ///
+    /// # Panics
+    /// When `nulls` is empty.
+    pub fn from_arrow_null_buffer(nulls: &NullBuffer) -> Self {
+        SuccinctBitVector::from_arrow_boolean_buffer(nulls.inner())
+    }
+}
+
+impl<S: AsRef<[u64]>> SuccinctBitVector<S> {
+    /// Exports the bits as Arrow's packed booleans (LSB-first, offset 0).
+    pub fn to_arrow_boolean_buffer(&self) -> BooleanBuffer {
+        let length = self.length();
+        let bytes = self
+            .storage()
+            .as_ref()
+            .iter()
+            .take(length.div_ceil(64) as usize)
+            .flat_map(|word| word.to_le_bytes().to_vec())
+            .take(length.div_ceil(8) as usize)
+            .collect::<Vec<u8>>();
+        BooleanBuffer::new(Buffer::from_vec(bytes), 0, length as usize)
+    }
+
+    /// Exports the bits as Arrow's validity bitmap: _i_-th row is non-null when _i_-th bit is '1'.
+    pub fn to_arrow_null_buffer(&self) -> NullBuffer {
+        NullBuffer::new(self.to_arrow_boolean_buffer())
+    }
+}
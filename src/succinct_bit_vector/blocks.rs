@@ -3,7 +3,7 @@ use crate::internal_data_structure::raw_bit_vector::RawBitVector;
 
 impl super::Blocks {
     /// Constructor.
-    pub fn new(rbv: &RawBitVector, i_chunk: u64, this_chunk_size: u16) -> Self {
+    pub fn new<S: AsRef<[u64]>>(rbv: &RawBitVector<S>, i_chunk: u64, this_chunk_size: u16) -> Self {
         let n = rbv.length();
         let chunk_size = Chunks::calc_chunk_size(n);
         let block_size = Blocks::calc_block_size(n);
@@ -3,7 +3,12 @@ use crate::internal_data_structure::raw_bit_vector::RawBitVector;
 
 impl super::Chunk {
     /// Constructor.
-    pub fn new(value: u64, length: u16, rbv: &RawBitVector, i_chunk: u64) -> Self {
+    pub fn new<S: AsRef<[u64]>>(
+        value: u64,
+        length: u16,
+        rbv: &RawBitVector<S>,
+        i_chunk: u64,
+    ) -> Self {
         let blocks = Blocks::new(rbv, i_chunk, length);
         Self {
             value,
@@ -3,7 +3,7 @@ use crate::internal_data_structure::raw_bit_vector::RawBitVector;
 
 impl super::Chunks {
     /// Constructor.
-    pub fn new(rbv: &RawBitVector) -> Self {
+    pub fn new<S: AsRef<[u64]>>(rbv: &RawBitVector<S>) -> Self {
         let n = rbv.length();
         let chunk_size: u16 = Chunks::calc_chunk_size(n);
         let chunks_cnt: u64 = Chunks::calc_chunks_cnt(n);
@@ -0,0 +1,378 @@
+use super::SuccinctBitVector;
+use crate::internal_data_structure::binary_io::{
+    invalid_data, read_u32, read_u64, read_u64_words, read_u8, write_u32, write_u64, write_u8,
+};
+use crate::internal_data_structure::raw_bit_vector::RawBitVector;
+use std::io::{self, Read, Write};
+
+/// `rank_support_v` stores a pair of words per 512 bits (8 words).
+const RANK_SUPERBLOCK_WORDS: usize = 8;
+
+/// Width of each relative count packed into the second word of a `rank_support_v` pair.
+const RANK_SECOND_LEVEL_WIDTH: usize = 9;
+
+/// `select_support_mcl` groups 4096 arguments ('1's) into a superblock.
+const SELECT_SUPERBLOCK_ARGS: usize = 4096;
+
+/// `select_support_mcl` samples every 64th argument in a non-long superblock.
+const SELECT_MINIBLOCK_ARGS: usize = 64;
+
+impl SuccinctBitVector {
+    /// Builds a `SuccinctBitVector` from sdsl-lite's serialized `bit_vector` (`int_vector<1>`).
+    ///
+    /// Rank/select directories are rebuilt as in [SuccinctBitVectorBuilder::build()](struct.SuccinctBitVectorBuilder.html#method.build).
+    /// Use [check_sdsl_rank_support_v()](#method.check_sdsl_rank_support_v) and [check_sdsl_select_support_mcl()](#method.check_sdsl_select_support_mcl) to make sure supports serialized alongside belong to the bit vector.
+    ///
+    /// # Errors
+    /// When `bit_vector` cannot be read, or is an empty bit vector.
+    pub fn from_sdsl_bit_vector<R: Read>(bit_vector: &mut R) -> io::Result<Self> {
+        let length = read_u64(bit_vector)?;
+        if length == 0 {
+            return Err(invalid_data(
+                "empty bit_vector is not supported".to_string(),
+            ));
+        }
+
+        let mut words = read_u64_words(bit_vector, length.div_ceil(64))?;
+        if let Some(last) = words.last_mut() {
+            *last &= RawBitVector::last_word_mask(length);
+        }
+        Ok(SuccinctBitVector::from_storage(words, length))
+    }
+}
+
+impl<S: AsRef<[u64]>> SuccinctBitVector<S> {
+    /// Writes the bits in sdsl-lite's serialized `bit_vector` (`int_vector<1>`) format.
+    pub fn write_sdsl_bit_vector<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        write_u64(w, self.length())?;
+        for word in self.sdsl_words() {
+            write_u64(w, word)?;
+        }
+        Ok(())
+    }
+
+    /// Writes sdsl-lite's serialized `rank_support_v<1>` for this bit vector.
+    ///
+    /// Loaded together with [write_sdsl_bit_vector()](#method.write_sdsl_bit_vector)'s output, sdsl-lite answers `rank` without constructing its support.
+    pub fn write_sdsl_rank_support_v<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let basic_block = self.sdsl_rank_basic_block();
+        write_int_vector(w, &basic_block, 64, false)
+    }
+
+    /// Writes sdsl-lite's serialized `select_support_mcl<1>` for this bit vector.
+    pub fn write_sdsl_select_support_mcl<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        let positions = self.sdsl_one_positions();
+        let arg_cnt = positions.len();
+        write_u64(w, arg_cnt as u64)?;
+        if arg_cnt == 0 {
+            return Ok(());
+        }
+
+        let (logn, logn2, logn4) = self.sdsl_select_logn();
+        write_u32(w, logn)?;
+        write_u32(w, logn2)?;
+        write_u32(w, logn4)?;
+
+        let superblocks: Vec<&[u64]> = positions.chunks(SELECT_SUPERBLOCK_ARGS).collect();
+        let superblock_heads: Vec<u64> = superblocks.iter().map(|sb| sb[0]).collect();
+        write_int_vector(w, &superblock_heads, logn as u8, true)?;
+
+        // `mini_or_long[i]` is '1' when i-th superblock is encoded with miniblocks.
+        // Like sdsl-lite's `init_slow()`, a superblock is long when its span is strictly greater than `logn4`.
+        let is_long: Vec<bool> = superblocks
+            .iter()
+            .map(|sb| sb[sb.len() - 1] - sb[0] > logn4 as u64)
+            .collect();
+        let mut mini_or_long = vec![0u64; RawBitVector::calc_words_cnt(superblocks.len() as u64)];
+        for (i, long) in is_long.iter().enumerate() {
+            if !long {
+                mini_or_long[i / 64] |= 1 << (i % 64);
+            }
+        }
+        write_u64(w, superblocks.len() as u64)?;
+        for word in mini_or_long {
+            write_u64(w, word)?;
+        }
+
+        for (sb, long) in superblocks.iter().zip(is_long) {
+            if long {
+                let mut entries = sb.to_vec();
+                entries.resize(SELECT_SUPERBLOCK_ARGS, 0);
+                let width = sdsl_hi(sb[sb.len() - 1]) + 1;
+                write_int_vector(w, &entries, width as u8, true)?;
+            } else {
+                let mut entries: Vec<u64> = sb
+                    .iter()
+                    .step_by(SELECT_MINIBLOCK_ARGS)
+                    .map(|pos| pos - sb[0])
+                    .collect();
+                entries.resize(SELECT_SUPERBLOCK_ARGS / SELECT_MINIBLOCK_ARGS, 0);
+                let width = sdsl_hi(sb[sb.len() - 1] - sb[0]) + 1;
+                write_int_vector(w, &entries, width as u8, true)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads sdsl-lite's serialized `rank_support_v<1>` and checks that it was built for this bit vector.
+    ///
+    /// # Errors
+    /// When `rank_support_v` cannot be read, or any of its counts differs from this bit vector's.
+    pub fn check_sdsl_rank_support_v<R: Read>(&self, rank_support_v: &mut R) -> io::Result<()> {
+        let basic_block = read_int_vector(rank_support_v, Some(64))?;
+        let expected = self.sdsl_rank_basic_block();
+        if basic_block.len() != expected.len() {
+            return Err(invalid_data(format!(
+                "rank_support_v has {} words while {} are expected",
+                basic_block.len(),
+                expected.len()
+            )));
+        }
+
+        let words_cnt = self.sdsl_words().count();
+        for (i_sb, (actual, expected)) in basic_block.chunks(2).zip(expected.chunks(2)).enumerate()
+        {
+            if actual[0] != expected[0] {
+                return Err(invalid_data(format!(
+                    "rank_support_v superblock {} counts {} '1's while {} are expected",
+                    i_sb, actual[0], expected[0]
+                )));
+            }
+            // Relative counts beyond the last word are meaningless.
+            let words_in_sb = words_cnt.saturating_sub(i_sb * RANK_SUPERBLOCK_WORDS);
+            for i_word in 1..RANK_SUPERBLOCK_WORDS.min(words_in_sb) {
+                let shift = 63 - RANK_SECOND_LEVEL_WIDTH * i_word;
+                let mask = (1u64 << RANK_SECOND_LEVEL_WIDTH) - 1;
+                if (actual[1] >> shift) & mask != (expected[1] >> shift) & mask {
+                    return Err(invalid_data(format!(
+                        "rank_support_v superblock {} has wrong count for word {}",
+                        i_sb, i_word
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads sdsl-lite's serialized `select_support_mcl<1>` and checks that it was built for this bit vector.
+    ///
+    /// # Errors
+    /// When `select_support_mcl` cannot be read, or any of its sampled positions differs from this bit vector's.
+    pub fn check_sdsl_select_support_mcl<R: Read>(
+        &self,
+        select_support_mcl: &mut R,
+    ) -> io::Result<()> {
+        let positions = self.sdsl_one_positions();
+        let arg_cnt = read_u64(select_support_mcl)?;
+        if arg_cnt != positions.len() as u64 {
+            return Err(invalid_data(format!(
+                "select_support_mcl has {} arguments while the bit vector has {} '1's",
+                arg_cnt,
+                positions.len()
+            )));
+        }
+        if arg_cnt == 0 {
+            return Ok(());
+        }
+
+        let logn = (
+            read_u32(select_support_mcl)?,
+            read_u32(select_support_mcl)?,
+            read_u32(select_support_mcl)?,
+        );
+        if logn != self.sdsl_select_logn() {
+            return Err(invalid_data(format!(
+                "select_support_mcl has (logn, logn2, logn4) = {:?} while {:?} is expected",
+                logn,
+                self.sdsl_select_logn()
+            )));
+        }
+
+        let superblocks: Vec<&[u64]> = positions.chunks(SELECT_SUPERBLOCK_ARGS).collect();
+        let superblock_heads = read_int_vector(select_support_mcl, None)?;
+        if superblock_heads.len() != superblocks.len() {
+            return Err(invalid_data(format!(
+                "select_support_mcl has {} superblocks while {} are expected",
+                superblock_heads.len(),
+                superblocks.len()
+            )));
+        }
+
+        let mini_or_long_len = read_u64(select_support_mcl)?;
+        if mini_or_long_len != superblocks.len() as u64 {
+            return Err(invalid_data(format!(
+                "select_support_mcl has {} mini_or_long flags while {} are expected",
+                mini_or_long_len,
+                superblocks.len()
+            )));
+        }
+        let mini_or_long = read_u64_words(select_support_mcl, mini_or_long_len.div_ceil(64))?;
+
+        for (i_sb, sb) in superblocks.iter().enumerate() {
+            if superblock_heads[i_sb] != sb[0] {
+                return Err(invalid_data(format!(
+                    "select_support_mcl superblock {} starts at {} while {} is expected",
+                    i_sb, superblock_heads[i_sb], sb[0]
+                )));
+            }
+
+            let is_mini = mini_or_long[i_sb / 64] & (1 << (i_sb % 64)) != 0;
+            let entries = read_int_vector(select_support_mcl, None)?;
+            let expected: Vec<u64> = if is_mini {
+                sb.iter()
+                    .step_by(SELECT_MINIBLOCK_ARGS)
+                    .map(|pos| pos - sb[0])
+                    .collect()
+            } else {
+                sb.to_vec()
+            };
+            if entries.len() < expected.len() || entries[..expected.len()] != expected[..] {
+                return Err(invalid_data(format!(
+                    "select_support_mcl superblock {} has wrong sampled positions",
+                    i_sb
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// `(logn, logn2, logn4)` of sdsl-lite's `select_support_mcl::initData()`, where the length is rounded up to a multiple of 64.
+    fn sdsl_select_logn(&self) -> (u32, u32, u32) {
+        let logn = sdsl_hi(self.length().div_ceil(64) * 64) + 1;
+        let logn2 = logn * logn;
+        (logn, logn2, logn2 * logn2)
+    }
+
+    /// Words in sdsl-lite's layout: _ceil(length / 64)_ words with bits beyond length cleared.
+    fn sdsl_words(&self) -> impl Iterator<Item = u64> + '_ {
+        let length = self.length();
+        let words_cnt = RawBitVector::calc_words_cnt(length);
+        self.storage().as_ref()[..words_cnt]
+            .iter()
+            .enumerate()
+            .map(move |(i, word)| {
+                if i + 1 == words_cnt {
+                    word & RawBitVector::last_word_mask(length)
+                } else {
+                    *word
+                }
+            })
+    }
+
+    /// Positions of every '1'.
+    fn sdsl_one_positions(&self) -> Vec<u64> {
+        let mut positions = vec![];
+        for (i_word, word) in self.sdsl_words().enumerate() {
+            let mut word = word;
+            while word != 0 {
+                positions.push(i_word as u64 * 64 + word.trailing_zeros() as u64);
+                word &= word - 1;
+            }
+        }
+        positions
+    }
+
+    /// `rank_support_v`'s `m_basic_block`: for each 512 bits, the cumulative count of '1's before them
+    /// followed by 7 packed 9-bit counts of '1's before each of the following words within the 512 bits.
+    fn sdsl_rank_basic_block(&self) -> Vec<u64> {
+        let words: Vec<u64> = self.sdsl_words().collect();
+        let mut basic_block = vec![0u64; (words.len() / RANK_SUPERBLOCK_WORDS + 1) * 2];
+
+        let mut cumulative = 0u64;
+        for (i_sb, sb_words) in words.chunks(RANK_SUPERBLOCK_WORDS).enumerate() {
+            basic_block[i_sb * 2] = cumulative;
+
+            let mut second_level = 0u64;
+            let mut sum = 0u64;
+            for (i_word, word) in sb_words.iter().enumerate() {
+                if i_word > 0 {
+                    second_level |= sum << (63 - RANK_SECOND_LEVEL_WIDTH * i_word);
+                }
+                sum += word.count_ones() as u64;
+            }
+            if sb_words.len() < RANK_SUPERBLOCK_WORDS {
+                second_level |= sum << (63 - RANK_SECOND_LEVEL_WIDTH * sb_words.len());
+            }
+            basic_block[i_sb * 2 + 1] = second_level;
+            cumulative += sum;
+        }
+        if words.len().is_multiple_of(RANK_SUPERBLOCK_WORDS) {
+            // Sentinel superblock holding the total count.
+            basic_block[words.len() / RANK_SUPERBLOCK_WORDS * 2] = cumulative;
+        }
+        basic_block
+    }
+}
+
+/// sdsl-lite's `bits::hi()`: position of the most significant '1', or 0 for 0.
+fn sdsl_hi(x: u64) -> u32 {
+    if x == 0 {
+        0
+    } else {
+        63 - x.leading_zeros()
+    }
+}
+
+/// Writes `int_vector<0>` of `width` when `variable_width`, or `int_vector<width>` otherwise.
+fn write_int_vector<W: Write>(
+    w: &mut W,
+    elements: &[u64],
+    width: u8,
+    variable_width: bool,
+) -> io::Result<()> {
+    let bits = elements.len() as u64 * width as u64;
+    write_u64(w, bits)?;
+    if variable_width {
+        write_u8(w, width)?;
+    }
+
+    let mut words = vec![0u64; bits.div_ceil(64) as usize];
+    for (i, element) in elements.iter().enumerate() {
+        let element = if width == 64 {
+            *element
+        } else {
+            element & ((1u64 << width) - 1)
+        };
+        let pos = i as u64 * width as u64;
+        let (i_word, offset) = ((pos / 64) as usize, pos % 64);
+        words[i_word] |= element << offset;
+        if offset + width as u64 > 64 {
+            words[i_word + 1] |= element >> (64 - offset);
+        }
+    }
+    for word in words {
+        write_u64(w, word)?;
+    }
+    Ok(())
+}
+
+/// Reads `int_vector<width>` when `fixed_width` is given, or `int_vector<0>` otherwise.
+fn read_int_vector<R: Read>(r: &mut R, fixed_width: Option<u8>) -> io::Result<Vec<u64>> {
+    let bits = read_u64(r)?;
+    let width = match fixed_width {
+        Some(width) => width,
+        None => read_u8(r)?,
+    };
+    if width == 0 || width > 64 {
+        return Err(invalid_data(format!("invalid int_vector width {}", width)));
+    }
+
+    let words = read_u64_words(r, bits.div_ceil(64))?;
+
+    let elements = (0..bits / width as u64)
+        .map(|i| {
+            let pos = i * width as u64;
+            let (i_word, offset) = ((pos / 64) as usize, pos % 64);
+            let mut element = words[i_word] >> offset;
+            if offset + width as u64 > 64 {
+                element |= words[i_word + 1] << (64 - offset);
+            }
+            if width == 64 {
+                element
+            } else {
+                element & ((1u64 << width) - 1)
+            }
+        })
+        .collect();
+    Ok(elements)
+}
@@ -0,0 +1,182 @@
+use super::{Block, Blocks, Chunk, Chunks, SuccinctBitVector};
+use crate::internal_data_structure::binary_io::{
+    invalid_data, read_u16, read_u64, read_u64_words, read_u8, write_u16, write_u64, write_u8,
+};
+use crate::internal_data_structure::popcount_table::PopcountTable;
+use crate::internal_data_structure::raw_bit_vector::RawBitVector;
+use crate::internal_data_structure::serialization_header::{
+    read_header, write_header, SerializedKind, FLAG_DIRECTORY,
+};
+use std::io::{self, Read, Write};
+use std::sync::Arc;
+
+impl<S: AsRef<[u64]>> SuccinctBitVector<S> {
+    /// Serializes the raw bits together with the rank/select directory, so that [deserialize()](#method.deserialize) does not rebuild it.
+    ///
+    /// # Examples
+    /// ```
+    /// use succinct_rs::{BitString, SuccinctBitVector, SuccinctBitVectorBuilder};
+    ///
+    /// let bv = SuccinctBitVectorBuilder::from_bit_string(BitString::new("0100_1")).build();
+    ///
+    /// let mut full = vec![];
+    /// bv.serialize(&mut full).unwrap();
+    /// let mut compact = vec![];
+    /// bv.serialize_compact(&mut compact).unwrap();
+    /// assert!(compact.len() < full.len());
+    ///
+    /// for buf in vec![full, compact] {
+    ///     let bv = SuccinctBitVector::deserialize(&mut &buf[..]).unwrap();
+    ///     assert_eq!(bv.select(2), Some(4));
+    /// }
+    /// ```
+    pub fn serialize<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        write_header(w, SerializedKind::SuccinctBitVector, FLAG_DIRECTORY)?;
+        self.serialize_body(w, true)
+    }
+
+    /// Serializes only the raw bits. [deserialize()](#method.deserialize) rebuilds the rank/select directory in _O(N)_ time.
+    pub fn serialize_compact<W: Write>(&self, w: &mut W) -> io::Result<()> {
+        write_header(w, SerializedKind::SuccinctBitVector, 0)?;
+        self.serialize_body(w, false)
+    }
+
+    /// Writes the length, the raw bits and (if `with_directory`) the directory.
+    pub(crate) fn serialize_body<W: Write>(
+        &self,
+        w: &mut W,
+        with_directory: bool,
+    ) -> io::Result<()> {
+        let length = self.length();
+        write_u64(w, length)?;
+        let words_cnt = RawBitVector::calc_words_cnt(length);
+        for (i, word) in self.storage().as_ref()[..words_cnt].iter().enumerate() {
+            if i + 1 == words_cnt {
+                write_u64(w, word & RawBitVector::last_word_mask(length))?;
+            } else {
+                write_u64(w, *word)?;
+            }
+        }
+
+        if with_directory {
+            write_u64(w, self.chunks.chunks_cnt)?;
+            for chunk in &self.chunks.chunks {
+                write_u64(w, chunk.value)?;
+                write_u16(w, chunk.length)?;
+                write_u16(w, chunk.blocks.blocks_cnt)?;
+                for block in &chunk.blocks.blocks {
+                    write_u16(w, block.value)?;
+                    write_u8(w, block.length)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl SuccinctBitVector {
+    /// Deserializes what [serialize()](#method.serialize) or [serialize_compact()](#method.serialize_compact) wrote.
+    ///
+    /// The header tells whether the rank/select directory is present; it is rebuilt when absent.
+    ///
+    /// # Errors
+    /// When `r` cannot be read, or its content is not a serialized `SuccinctBitVector`.
+    pub fn deserialize<R: Read>(r: &mut R) -> io::Result<Self> {
+        let flags = read_header(r, SerializedKind::SuccinctBitVector)?;
+        SuccinctBitVector::deserialize_body(r, flags & FLAG_DIRECTORY != 0)
+    }
+
+    /// Reads what `serialize_body()` wrote.
+    pub(crate) fn deserialize_body<R: Read>(r: &mut R, with_directory: bool) -> io::Result<Self> {
+        let length = read_u64(r)?;
+        if length == 0 {
+            return Err(invalid_data("length must be > 0".to_string()));
+        }
+        let words = read_u64_words(r, length.div_ceil(64))?;
+        let rbv = RawBitVector::from_words(words, length);
+
+        if !with_directory {
+            return Ok(SuccinctBitVector::from_raw_bit_vector(rbv));
+        }
+
+        let chunk_size = Chunks::calc_chunk_size(length);
+        let block_size = Blocks::calc_block_size(length);
+        let chunks_cnt = read_u64(r)?;
+        if chunks_cnt != Chunks::calc_chunks_cnt(length) {
+            return Err(invalid_data(format!(
+                "{} chunks are serialized while {} are expected",
+                chunks_cnt,
+                Chunks::calc_chunks_cnt(length)
+            )));
+        }
+
+        // Values are checked against popcounts of the words, as rank() trusts them.
+        let mut chunks = Vec::with_capacity(chunks_cnt as usize);
+        let mut expected_value = 0;
+        for i_chunk in 0..chunks_cnt {
+            let value = read_u64(r)?;
+            let this_chunk_size = read_u16(r)?;
+            let expected_chunk_size = (length - i_chunk * chunk_size as u64).min(chunk_size as u64);
+            if this_chunk_size as u64 != expected_chunk_size {
+                return Err(invalid_data(format!(
+                    "chunk {} has length {} while {} is expected",
+                    i_chunk, this_chunk_size, expected_chunk_size
+                )));
+            }
+            expected_value += rbv
+                .copy_sub(i_chunk * chunk_size as u64, this_chunk_size as u64)
+                .popcount();
+            if value != expected_value {
+                return Err(invalid_data(format!(
+                    "chunk {} has value {} while {} is expected",
+                    i_chunk, value, expected_value
+                )));
+            }
+
+            let blocks_cnt = read_u16(r)?;
+            if blocks_cnt != this_chunk_size.div_ceil(block_size as u16) {
+                return Err(invalid_data(format!(
+                    "chunk {} has {} blocks while {} are expected",
+                    i_chunk,
+                    blocks_cnt,
+                    this_chunk_size.div_ceil(block_size as u16)
+                )));
+            }
+            let mut blocks = Vec::with_capacity(blocks_cnt as usize);
+            let mut expected_block_value = 0;
+            for i_block in 0..blocks_cnt {
+                let block_value = read_u16(r)?;
+                let block_length = read_u8(r)?;
+                // Same as Blocks::new(): only the last block of the bit vector can be shorter.
+                let i_rbv = i_chunk * chunk_size as u64 + i_block as u64 * block_size as u64;
+                let expected_block_length = (length - i_rbv).min(block_size as u64);
+                if block_length as u64 != expected_block_length {
+                    return Err(invalid_data(format!(
+                        "block {} in chunk {} has length {} while {} is expected",
+                        i_block, i_chunk, block_length, expected_block_length
+                    )));
+                }
+                expected_block_value += rbv.copy_sub(i_rbv, block_length as u64).popcount() as u16;
+                if block_value != expected_block_value {
+                    return Err(invalid_data(format!(
+                        "block {} in chunk {} has value {} while {} is expected",
+                        i_block, i_chunk, block_value, expected_block_value
+                    )));
+                }
+                blocks.push(Block::new(block_value, block_length));
+            }
+
+            chunks.push(Chunk {
+                value,
+                blocks: Blocks { blocks, blocks_cnt },
+                length: this_chunk_size,
+            });
+        }
+
+        Ok(SuccinctBitVector {
+            rbv,
+            chunks: Arc::new(Chunks { chunks, chunks_cnt }),
+            table: Arc::new(PopcountTable::new(block_size)),
+        })
+    }
+}
@@ -1,6 +1,49 @@
 use super::{Blocks, Chunks, SuccinctBitVector};
+use crate::internal_data_structure::popcount_table::PopcountTable;
+use crate::internal_data_structure::raw_bit_vector::RawBitVector;
+use std::fmt;
+use std::sync::Arc;
+
+/// The number of leading bits shown by `Debug` and `Display`.
+const FMT_LEADING_BITS: u64 = 64;
+
+impl<S: AsRef<[u64]>> SuccinctBitVector<S> {
+    /// Builds a `SuccinctBitVector` of `length` bits on top of already packed `storage` in _O(N)_ time.
+    ///
+    /// _i_-th bit is the _(i % 64)_-th least significant bit of _`storage`[i / 64]_. Bits beyond `length` are ignored.
+    ///
+    /// # Panics
+    /// When:
+    /// - _`length` == 0_.
+    /// - `storage` has less than _ceil(`length` / 64)_ words.
+    pub fn from_storage(storage: S, length: u64) -> Self {
+        SuccinctBitVector::from_raw_bit_vector(RawBitVector::from_words(storage, length))
+    }
+
+    /// Returns the length of the `SuccinctBitVector`.
+    pub fn length(&self) -> u64 {
+        self.rbv.length()
+    }
+
+    /// Returns the storage holding the raw bits.
+    pub fn storage(&self) -> &S {
+        self.rbv.words()
+    }
+
+    /// Consumes the `SuccinctBitVector` and returns the storage holding the raw bits.
+    pub fn into_storage(self) -> S {
+        self.rbv.into_words()
+    }
+
+    /// Moves the raw bits into another storage (e.g. `Vec<u64>` into `Arc<[u64]>`) without rebuilding the index.
+    pub fn map_storage<T: AsRef<[u64]>, F: FnOnce(S) -> T>(self, f: F) -> SuccinctBitVector<T> {
+        SuccinctBitVector {
+            rbv: self.rbv.map_words(f),
+            chunks: self.chunks,
+            table: self.table,
+        }
+    }
 
-impl SuccinctBitVector {
     /// Returns `i`-th element of the `SuccinctBitVector`.
     ///
     /// # Panics
@@ -135,7 +178,7 @@ impl SuccinctBitVector {
         let n = self.rbv.length();
         assert!(num <= n);
 
-        if num == 0 || num == 1 && self.access(0) == false {
+        if num == 0 || num == 1 && !self.access(0) {
             return Some(0);
         }
         if self.rank0(n - 1) < num {
@@ -154,6 +197,55 @@ impl SuccinctBitVector {
         }
         Some(ok)
     }
+
+    /// Builds the index (chunks, blocks and table) on `rbv`.
+    pub(super) fn from_raw_bit_vector(rbv: RawBitVector<S>) -> Self {
+        let chunks = Arc::new(Chunks::new(&rbv));
+        let table = Arc::new(PopcountTable::new(Blocks::calc_block_size(rbv.length())));
+        SuccinctBitVector { rbv, chunks, table }
+    }
+
+    /// Returns up to `FMT_LEADING_BITS` leading bits as '0'/'1' string, followed by "..." if truncated.
+    fn leading_bits(&self) -> String {
+        let n = self.length();
+        let mut s = (0..n.min(FMT_LEADING_BITS))
+            .map(|i| if self.access(i) { '1' } else { '0' })
+            .collect::<String>();
+        if n > FMT_LEADING_BITS {
+            s.push_str("...");
+        }
+        s
+    }
+}
+
+impl<S: AsRef<[u64]>> fmt::Debug for SuccinctBitVector<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SuccinctBitVector")
+            .field("length", &self.length())
+            .field("popcount", &self.rank(self.length() - 1))
+            .field("leading_bits", &self.leading_bits())
+            .finish()
+    }
+}
+
+/// Shows the leading bits, length and popcount.
+///
+/// ```
+/// use succinct_rs::{BitString, SuccinctBitVectorBuilder};
+///
+/// let bv = SuccinctBitVectorBuilder::from_bit_string(BitString::new("0100_1")).build();
+/// assert_eq!(format!("{}", bv), "01001 (length: 5, popcount: 2)");
+/// ```
+impl<S: AsRef<[u64]>> fmt::Display for SuccinctBitVector<S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} (length: {}, popcount: {})",
+            self.leading_bits(),
+            self.length(),
+            self.rank(self.length() - 1)
+        )
+    }
 }
 
 #[cfg(test)]
@@ -335,3 +427,54 @@ mod select0_failure_tests {
         let _ = bv.select0(3);
     }
 }
+
+#[cfg(test)]
+mod fmt_success_tests {
+    use super::super::{BitString, SuccinctBitVectorBuilder};
+
+    macro_rules! parameterized_tests {
+        ($($name:ident: $value:expr,)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let (in_bv_str, expected_display, expected_debug) = $value;
+                let bv = SuccinctBitVectorBuilder::from_bit_string(BitString::new(in_bv_str)).build();
+                assert_eq!(format!("{}", bv), expected_display);
+                assert_eq!(format!("{:?}", bv), expected_debug);
+            }
+        )*
+        }
+    }
+
+    parameterized_tests! {
+        t1: ("0", "0 (length: 1, popcount: 0)",
+             "SuccinctBitVector { length: 1, popcount: 0, leading_bits: \"0\" }"),
+        t5: ("01001", "01001 (length: 5, popcount: 2)",
+             "SuccinctBitVector { length: 5, popcount: 2, leading_bits: \"01001\" }"),
+        t64: ("11111111_00000000_11111111_00000000_11111111_00000000_11111111_00000000",
+              "1111111100000000111111110000000011111111000000001111111100000000 (length: 64, popcount: 32)",
+              "SuccinctBitVector { length: 64, popcount: 32, leading_bits: \"1111111100000000111111110000000011111111000000001111111100000000\" }"),
+        t65: ("11111111_00000000_11111111_00000000_11111111_00000000_11111111_00000000_1",
+              "1111111100000000111111110000000011111111000000001111111100000000... (length: 65, popcount: 33)",
+              "SuccinctBitVector { length: 65, popcount: 33, leading_bits: \"1111111100000000111111110000000011111111000000001111111100000000...\" }"),
+    }
+}
+
+#[cfg(test)]
+mod clone_success_tests {
+    use super::super::{BitString, SuccinctBitVector, SuccinctBitVectorBuilder};
+    use std::sync::Arc;
+
+    #[test]
+    fn clones_share_storage_and_index() {
+        let bv: SuccinctBitVector<Arc<[u64]>> =
+            SuccinctBitVectorBuilder::from_bit_string(BitString::new("0100_1000_0000_1"))
+                .build()
+                .map_storage(Arc::from);
+        let cloned = bv.clone();
+        assert!(Arc::ptr_eq(bv.storage(), cloned.storage()));
+        assert!(Arc::ptr_eq(&bv.chunks, &cloned.chunks));
+        assert!(Arc::ptr_eq(&bv.table, &cloned.table));
+        assert_eq!(cloned.rank(12), 3);
+    }
+}
@@ -1,5 +1,4 @@
-use super::{BitString, Blocks, Chunks, SuccinctBitVector, SuccinctBitVectorSeed};
-use crate::internal_data_structure::popcount_table::PopcountTable;
+use super::{BitString, SuccinctBitVector, SuccinctBitVectorSeed};
 use crate::internal_data_structure::raw_bit_vector::RawBitVector;
 use std::collections::HashSet;
 
@@ -20,6 +19,14 @@ impl super::SuccinctBitVectorBuilder {
         }
     }
 
+    /// Prepares a bit vector from already packed bits.
+    pub(crate) fn from_raw_bit_vector(rbv: RawBitVector) -> Self {
+        Self {
+            seed: SuccinctBitVectorSeed::Raw(rbv),
+            bits_set: HashSet::new(),
+        }
+    }
+
     /// Set 1 to i-th bit.
     ///
     /// # Panics
@@ -40,6 +47,11 @@ impl super::SuccinctBitVectorBuilder {
     ///
     /// _WARNING_: Do not use with [from_bit_string()](#method.from_bit_string). It leads to string concatenation and should be too slow.
     pub fn add_bit(&mut self, b: bool) -> &mut Self {
+        if let SuccinctBitVectorSeed::Raw(rbv) = &mut self.seed {
+            rbv.push(b);
+            return self;
+        }
+
         let length = self.current_length();
         if b {
             self.bits_set.insert(length);
@@ -49,6 +61,7 @@ impl super::SuccinctBitVectorBuilder {
             SuccinctBitVectorSeed::BitStr(bs) => {
                 SuccinctBitVectorSeed::BitStr(BitString::new(&format!("{}0", bs.str())))
             }
+            SuccinctBitVectorSeed::Raw(_) => unreachable!(),
         };
         self
     }
@@ -63,20 +76,20 @@ impl super::SuccinctBitVectorBuilder {
         let mut rbv = match &self.seed {
             SuccinctBitVectorSeed::Length(n) => RawBitVector::from_length(*n),
             SuccinctBitVectorSeed::BitStr(bs) => RawBitVector::from_bit_string(bs),
+            SuccinctBitVectorSeed::Raw(rbv) => rbv.clone(),
         };
         for bit in &self.bits_set {
             rbv.set_bit(*bit)
         }
 
-        let chunks = Chunks::new(&rbv);
-        let table = PopcountTable::new(Blocks::calc_block_size(rbv.length()));
-        SuccinctBitVector { rbv, chunks, table }
+        SuccinctBitVector::from_raw_bit_vector(rbv)
     }
 
     fn current_length(&self) -> u64 {
         match &self.seed {
             SuccinctBitVectorSeed::Length(n) => *n,
             SuccinctBitVectorSeed::BitStr(bs) => bs.str().len() as u64,
+            SuccinctBitVectorSeed::Raw(rbv) => rbv.length(),
         }
     }
 }
@@ -0,0 +1,87 @@
+#![cfg(feature = "arrow")]
+
+use arrow_buffer::{BooleanBuffer, NullBuffer};
+use succinct_rs::{BitString, SuccinctBitVector, SuccinctBitVectorBuilder};
+
+fn bools(s: &str) -> Vec<bool> {
+    BitString::new(s).str().chars().map(|c| c == '1').collect()
+}
+
+#[test]
+fn from_boolean_buffer_respects_offset_and_length() {
+    let s =
+        "0110_1001_1100_0101_1111_0000_1010_0101_0011_1100_0110_1001_1100_0101_1111_0000_1010_0101";
+    let all = bools(s);
+    let buffer = BooleanBuffer::from(all.clone());
+
+    for offset in 0..all.len() {
+        for len in 1..=(all.len() - offset) {
+            let bv = SuccinctBitVector::from_arrow_boolean_buffer(&buffer.slice(offset, len));
+            assert_eq!(bv.length(), len as u64);
+
+            let mut rank = 0;
+            for i in 0..len {
+                assert_eq!(
+                    bv.access(i as u64),
+                    all[offset + i],
+                    "offset={}, len={}, i={}",
+                    offset,
+                    len,
+                    i
+                );
+                if all[offset + i] {
+                    rank += 1;
+                }
+                assert_eq!(bv.rank(i as u64), rank);
+            }
+        }
+    }
+}
+
+#[test]
+fn select_kth_non_null_row() {
+    let validity = bools("0101_1000_0011");
+    let nulls = NullBuffer::from(validity.clone());
+    let bv = SuccinctBitVector::from_arrow_null_buffer(&nulls);
+
+    let non_null_rows = validity
+        .iter()
+        .enumerate()
+        .filter(|(_, valid)| **valid)
+        .map(|(i, _)| i as u64)
+        .collect::<Vec<u64>>();
+    for (k, row) in non_null_rows.iter().enumerate() {
+        assert_eq!(bv.select(k as u64 + 1), Some(*row));
+    }
+    assert_eq!(bv.select(non_null_rows.len() as u64 + 1), None);
+}
+
+#[test]
+fn export_round_trip() {
+    for s in &[
+        "0",
+        "1",
+        "01001",
+        "0110_1001_1100_0101_1111_0000_1010_0101_0011_1100_0110_1001_1100_0101_1111_0000_1010_0101",
+    ] {
+        let bv = SuccinctBitVectorBuilder::from_bit_string(BitString::new(s)).build();
+
+        let buffer = bv.to_arrow_boolean_buffer();
+        assert_eq!(buffer.iter().collect::<Vec<bool>>(), bools(s));
+
+        let nulls = bv.to_arrow_null_buffer();
+        assert_eq!(
+            nulls.null_count() as u64,
+            bv.length() - bv.rank(bv.length() - 1)
+        );
+
+        let reimported = SuccinctBitVector::from_arrow_boolean_buffer(&buffer);
+        assert_eq!(reimported.storage(), bv.storage());
+    }
+}
+
+#[test]
+#[should_panic]
+fn from_empty_boolean_buffer() {
+    let _ = SuccinctBitVector::from_arrow_boolean_buffer(&BooleanBuffer::new_set(0));
+}
@@ -0,0 +1,34 @@
+// Generates the fixtures in this directory with sdsl-lite.
+//
+// The checked-in fixtures were written by transcribe.py, an independent transcription of the
+// same sdsl-lite code; running this program should reproduce them byte for byte.
+//
+//   g++ -std=c++11 -O2 generate.cpp -o generate -lsdsl && ./generate
+#include <sdsl/bit_vectors.hpp>
+#include <functional>
+#include <string>
+
+using namespace sdsl;
+
+static void write(const std::string& name, uint64_t n, std::function<bool(uint64_t)> bit)
+{
+    bit_vector bv(n, 0);
+    for (uint64_t i = 0; i < n; ++i) {
+        bv[i] = bit(i);
+    }
+    rank_support_v<1> rs(&bv);
+    select_support_mcl<1> ss(&bv);
+
+    store_to_file(bv, name + ".bit_vector");
+    store_to_file(rs, name + ".rank_support_v");
+    store_to_file(ss, name + ".select_support_mcl");
+}
+
+int main()
+{
+    const std::string small = "0000100001000001000001001100000000100000000001011010000000010000001";
+    write("small", small.size(), [&](uint64_t i) { return small[i] == '1'; });
+    write("dense", 10000, [](uint64_t i) { return (i * i + 3 * i) % 7 < 3; });
+    write("sparse", 1 << 20, [](uint64_t i) { return i % 61 == 0; });
+    return 0;
+}
@@ -0,0 +1,119 @@
+# Independent Python transcription of sdsl-lite's int_vector::serialize,
+# rank_support_v::init/serialize and select_support_mcl::init_slow/serialize.
+# The checked-in fixtures were written by this script, since sdsl-lite was not at hand;
+# generate.cpp writes the same files with sdsl-lite itself and should replace them.
+#
+#   python3 transcribe.py .
+import struct, sys
+
+def hi(x):
+    return 0 if x == 0 else x.bit_length() - 1
+
+class IntVector:
+    def __init__(self, n, width):
+        self.width = 64 if width == 0 or width > 64 else width
+        self.vals = [0] * n
+    def bytes(self, variable):
+        bits = len(self.vals) * self.width
+        out = struct.pack('<Q', bits)
+        if variable:
+            out += struct.pack('<B', self.width)
+        nwords = (bits + 63) // 64
+        big = 0
+        for i, v in enumerate(self.vals):
+            big |= (v & ((1 << self.width) - 1)) << (i * self.width)
+        for w in range(nwords):
+            out += struct.pack('<Q', (big >> (64 * w)) & ((1 << 64) - 1))
+        return out
+
+def bit_vector_bytes(bits):
+    n = len(bits)
+    nwords = (n + 63) // 64
+    words = [0] * nwords
+    for i, b in enumerate(bits):
+        if b: words[i // 64] |= 1 << (i % 64)
+    return struct.pack('<Q', n) + b''.join(struct.pack('<Q', w) for w in words), words
+
+def rank_support_v_bytes(words):
+    W = len(words)
+    bb = [0] * (((W * 64 >> 9) + 1) << 1)
+    j = 0
+    bb[0] = bb[1] = 0
+    s = bin(words[0]).count('1')
+    second = 0
+    i = 1
+    while i < W:
+        if not (i & 7):
+            j += 2
+            bb[j - 1] = second
+            bb[j] = bb[j - 2] + s
+            second = s = 0
+        else:
+            second |= s << (63 - 9 * (i & 7))
+        s += bin(words[i]).count('1')
+        i += 1
+    if i & 7:
+        second |= s << (63 - 9 * (i & 7))
+        bb[j + 1] = second
+    else:
+        j += 2
+        bb[j - 1] = second
+        bb[j] = bb[j - 2] + s
+        bb[j + 1] = 0
+    iv = IntVector(len(bb), 64)
+    iv.vals = [v & ((1 << 64) - 1) for v in bb]
+    return iv.bytes(False)
+
+def select_support_mcl_bytes(bits):
+    n = len(bits)
+    arg_cnt = sum(bits)
+    out = struct.pack('<Q', arg_cnt)
+    if arg_cnt == 0:
+        return out
+    logn = hi(((n + 63) >> 6) << 6) + 1
+    logn2 = logn * logn
+    logn4 = logn2 * logn2
+    sb = (arg_cnt + 4095) // 4096
+    superblock = IntVector(sb, logn)
+    longsb = [None] * sb
+    mini = [None] * sb
+    argpos = [0] * 4096
+    cnt = 0
+    sbc = 0
+    for i in range(n):
+        if bits[i]:
+            argpos[cnt % 4096] = i
+            cnt += 1
+            if cnt % 4096 == 0 or cnt == arg_cnt:
+                superblock.vals[sbc] = argpos[0]
+                last = (cnt - 1) % 4096
+                diff = argpos[last] - argpos[0]
+                if diff > logn4:
+                    iv = IntVector(4096, hi(argpos[last]) + 1)
+                    for k in range(last + 1):
+                        iv.vals[k] = argpos[k]
+                    longsb[sbc] = iv
+                else:
+                    iv = IntVector(64, hi(diff) + 1)
+                    for k in range(0, last + 1, 64):
+                        iv.vals[k // 64] = argpos[k] - argpos[0]
+                    mini[sbc] = iv
+                sbc += 1
+    out += struct.pack('<III', logn, logn2, logn4)
+    out += superblock.bytes(True)
+    mol = [mini[k] is not None for k in range(sb)]
+    out += bit_vector_bytes(mol)[0]
+    for k in range(sb):
+        out += (mini[k] if mol[k] else longsb[k]).bytes(True)
+    return out
+
+def write(name, bits):
+    bv, words = bit_vector_bytes(bits)
+    d = sys.argv[1]
+    open(f'{d}/{name}.bit_vector', 'wb').write(bv)
+    open(f'{d}/{name}.rank_support_v', 'wb').write(rank_support_v_bytes(words))
+    open(f'{d}/{name}.select_support_mcl', 'wb').write(select_support_mcl_bytes(bits))
+
+write('small', [c == '1' for c in "0000100001000001000001001100000000100000000001011010000000010000001"])
+write('dense', [(i * i + 3 * i) % 7 < 3 for i in range(10000)])
+write('sparse', [i % 61 == 0 for i in range(1 << 20)])
@@ -1,5 +1,6 @@
 mod louds_feature_test {
-    use succinct_rs::{BitString, LoudsBuilder, LoudsNodeNum};
+    use rand::prelude::*;
+    use succinct_rs::{BitString, Louds, LoudsBuilder, LoudsForestBuilder, LoudsNodeNum};
 
     #[test]
     fn fuzzing_test() {
@@ -36,14 +37,397 @@ mod louds_feature_test {
                 eprintln!("NodeNum({:?})", raw_node_num);
 
                 // index(node_num_to_index(node_num)) == node_num
-                let index = louds.node_num_to_index(&node_num);
-                assert_eq!(louds.index_to_node_num(&index), node_num);
+                let index = louds.node_num_to_index(node_num);
+                assert_eq!(louds.index_to_node_num(index), node_num);
 
                 // `node_num`'s children have `node_num` as parent.
-                for child_index in louds.parent_to_children(&node_num) {
-                    assert_eq!(louds.child_to_parent(&child_index), node_num);
+                for child_index in louds.parent_to_children(node_num) {
+                    assert_eq!(louds.child_to_parent(child_index), node_num);
                 }
             }
         }
     }
+
+    /// Random LBS for the tests below, generated as in `fuzzing_test()`.
+    fn generate_lbs(rng: &mut ThreadRng) -> BitString {
+        let mut s = String::from("10");
+        let (mut cnt0, mut cnt1) = (1u64, 1u64);
+        while cnt0 < cnt1 + 1 {
+            let r = rng.gen::<f64>();
+            if r < 0.6 {
+                s = format!("{}{}", s, "0");
+                cnt0 += 1;
+            } else {
+                s = format!("{}{}", s, "1");
+                cnt1 += 1;
+            }
+        }
+        BitString::new(&s)
+    }
+
+    /// Degree sequence in BFS order, which determines the whole LBS (unlike `Display`, which shows only its head).
+    fn degrees(louds: &Louds) -> Vec<u64> {
+        louds.bfs().map(|node_num| louds.degree(node_num)).collect()
+    }
+
+    /// Traverses with recursion on `parent_to_children()`, appending `(node, depth)` in pre-order and post-order.
+    fn naive_dfs(
+        louds: &Louds,
+        node_num: u64,
+        depth: u64,
+        preorder: &mut Vec<(u64, u64)>,
+        postorder: &mut Vec<(u64, u64)>,
+    ) {
+        preorder.push((node_num, depth));
+        for child_index in louds.parent_to_children(LoudsNodeNum::new(node_num)) {
+            let child = louds.index_to_node_num(child_index);
+            naive_dfs(louds, child.value(), depth + 1, preorder, postorder);
+        }
+        postorder.push((node_num, depth));
+    }
+
+    #[test]
+    fn traversal_fuzzing_test() {
+        let samples = 100;
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..samples {
+            let bs = generate_lbs(&mut rng);
+            eprintln!("build(): LBS = \"{}\"", bs.str());
+            let louds = LoudsBuilder::from_bit_string(bs).build();
+
+            let (mut preorder, mut postorder) = (vec![], vec![]);
+            naive_dfs(&louds, 1, 0, &mut preorder, &mut postorder);
+            let mut bfs = preorder.clone();
+            bfs.sort();
+
+            let collect = |it: succinct_rs::LoudsTraversal| -> Vec<(u64, u64)> {
+                it.with_depth().map(|(n, d)| (n.value(), d)).collect()
+            };
+            assert_eq!(collect(louds.bfs()), bfs);
+            assert_eq!(collect(louds.dfs_preorder()), preorder);
+            assert_eq!(collect(louds.dfs_postorder()), postorder);
+        }
+    }
+
+    #[test]
+    fn traversal_deep_tree_test() {
+        // A path of 100,000 nodes: recursion of this depth would overflow the call stack of a test thread.
+        let n = 100_000;
+        let lbs = "10".repeat(n) + "0";
+        let louds = LoudsBuilder::from_bit_string(BitString::new(&lbs)).build();
+        assert_eq!(louds.height(), n as u64 - 1);
+
+        let mut cnt = 0;
+        for (i, (node_num, depth)) in louds.dfs_postorder().with_depth().enumerate() {
+            assert_eq!(node_num.value(), (n - i) as u64);
+            assert_eq!(depth, (n - i - 1) as u64);
+            cnt += 1;
+        }
+        assert_eq!(cnt, n);
+        assert_eq!(louds.dfs_preorder().count(), n);
+    }
+
+    #[test]
+    fn from_children_lists_fuzzing_test() {
+        let samples = 100;
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..samples {
+            let bs = generate_lbs(&mut rng);
+            eprintln!("build(): LBS = \"{}\"", bs.str());
+            let louds = LoudsBuilder::from_bit_string(bs).build();
+
+            // Assign random ids to nodes and rebuild from the adjacency lists.
+            let n_nodes = louds.bfs().count();
+            let mut ids: Vec<usize> = (0..n_nodes).collect();
+            ids.shuffle(&mut rng);
+            let mut children = vec![vec![]; n_nodes];
+            for node_num in louds.bfs() {
+                for child_index in louds.children(node_num) {
+                    let child = louds.index_to_node_num(child_index);
+                    children[ids[node_num.value() as usize - 1]]
+                        .push(ids[child.value() as usize - 1]);
+                }
+            }
+
+            let (builder, node_nums) = LoudsBuilder::from_children_lists(&children, ids[0]);
+            assert_eq!(degrees(&builder.build()), degrees(&louds));
+            for (k, id) in ids.iter().enumerate() {
+                assert_eq!(node_nums[*id], LoudsNodeNum::new(k as u64 + 1));
+            }
+        }
+    }
+
+    #[test]
+    fn from_tree_test() {
+        struct Node {
+            id: u64,
+            children: Vec<Node>,
+        }
+        fn node(id: u64, children: Vec<Node>) -> Node {
+            Node { id, children }
+        }
+
+        // The tree in `Louds`' doc, with ids = NodeNum * 10.
+        let root = node(
+            10,
+            vec![
+                node(20, vec![node(50, vec![])]),
+                node(30, vec![]),
+                node(
+                    40,
+                    vec![
+                        node(60, vec![]),
+                        node(70, vec![node(90, vec![])]),
+                        node(80, vec![node(100, vec![]), node(110, vec![])]),
+                    ],
+                ),
+            ],
+        );
+        let (builder, nodes) = LoudsBuilder::from_tree(&root, |n| n.children.iter());
+        let louds = builder.build();
+        let expected =
+            LoudsBuilder::from_bit_string(BitString::new("10_1110_10_0_1110_0_0_10_110_0_0_0"))
+                .build();
+        assert_eq!(degrees(&louds), degrees(&expected));
+        for (n, node_num) in nodes {
+            assert_eq!(n.id, node_num.value() * 10);
+        }
+    }
+
+    #[test]
+    fn from_degrees_fuzzing_test() {
+        let samples = 100;
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..samples {
+            let bs = generate_lbs(&mut rng);
+            eprintln!("build(): LBS = \"{}\"", bs.str());
+            let louds = LoudsBuilder::from_bit_string(bs).build();
+
+            let rebuilt =
+                LoudsBuilder::from_degrees(degrees(&louds).into_iter().map(|d| d as usize)).build();
+            assert_eq!(degrees(&rebuilt), degrees(&louds));
+        }
+    }
+
+    /// LCA with plain parent pointers: marks `a`'s ancestors, then climbs from `b` until a marked one.
+    fn naive_lca(parents: &[u64], a: u64, b: u64) -> u64 {
+        let mut marked = vec![false; parents.len()];
+        let mut x = a;
+        loop {
+            marked[x as usize] = true;
+            if x == 1 {
+                break;
+            }
+            x = parents[x as usize];
+        }
+        let mut y = b;
+        while !marked[y as usize] {
+            y = parents[y as usize];
+        }
+        y
+    }
+
+    #[test]
+    fn lca_fuzzing_test() {
+        let samples = 100;
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..samples {
+            let bs = generate_lbs(&mut rng);
+            eprintln!("build(): LBS = \"{}\"", bs.str());
+            let louds = LoudsBuilder::from_bit_string(bs).build();
+
+            // parents[node_num]; parents[0] and parents[1] are unused.
+            let n_nodes = louds.bfs().count() as u64;
+            let mut parents = vec![0; n_nodes as usize + 1];
+            for node_num in 2..=n_nodes {
+                let index = louds.node_num_to_index(LoudsNodeNum::new(node_num));
+                parents[node_num as usize] = louds.child_to_parent(index).value();
+            }
+
+            let pairs: Vec<(LoudsNodeNum, LoudsNodeNum)> = (0..50)
+                .map(|_| {
+                    (
+                        LoudsNodeNum::new(rng.gen_range(1, n_nodes + 1)),
+                        LoudsNodeNum::new(rng.gen_range(1, n_nodes + 1)),
+                    )
+                })
+                .collect();
+            let lcas = louds.lca_batch(&pairs);
+            for ((a, b), lca) in pairs.iter().zip(lcas) {
+                let expected = naive_lca(&parents, a.value(), b.value());
+                assert_eq!(lca, LoudsNodeNum::new(expected));
+                assert_eq!(louds.lca(a, b), lca);
+            }
+        }
+    }
+
+    #[test]
+    fn forest_fuzzing_test() {
+        let samples = 100;
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..samples {
+            let n_trees = rng.gen_range(1, 6);
+            let trees: Vec<Louds> = (0..n_trees)
+                .map(|_| {
+                    let bs = generate_lbs(&mut rng);
+                    eprintln!("build(): LBS = \"{}\"", bs.str());
+                    LoudsBuilder::from_bit_string(bs).build()
+                })
+                .collect();
+            let forest = LoudsForestBuilder::from_trees(&trees).build();
+            assert_eq!(forest.trees_cnt(), n_trees);
+
+            let roots: Vec<LoudsNodeNum> = forest.roots().collect();
+            for (t, tree) in trees.iter().enumerate() {
+                let t = t as u64;
+                assert_eq!(degrees(&forest.tree(t)), degrees(tree));
+
+                let offset = forest.tree_node_nums(t).start.value() - 1;
+                assert_eq!(roots[t as usize], LoudsNodeNum::new(offset + 1));
+                for node_num in tree.bfs() {
+                    let global = LoudsNodeNum::new(node_num.value() + offset);
+                    assert_eq!(forest.tree_of(global), t);
+
+                    let parent = forest.parent(global).map(|p| p.value() - offset);
+                    let expected_parent = tree.ancestors(node_num).next().map(|p| p.value());
+                    assert_eq!(parent, expected_parent);
+
+                    let children: Vec<u64> = forest
+                        .children(global)
+                        .map(|index| forest.index_to_node_num(index).value() - offset)
+                        .collect();
+                    let expected_children: Vec<u64> = tree
+                        .children(node_num)
+                        .map(|index| tree.index_to_node_num(index).value())
+                        .collect();
+                    assert_eq!(children, expected_children);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn subtree_fuzzing_test() {
+        let samples = 100;
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..samples {
+            let bs = generate_lbs(&mut rng);
+            eprintln!("build(): LBS = \"{}\"", bs.str());
+            let louds = LoudsBuilder::from_bit_string(bs).build();
+            let n_nodes = louds.bfs().count() as u64;
+
+            for _ in 0..10 {
+                let root = LoudsNodeNum::new(rng.gen_range(1, n_nodes + 1));
+                let (subtree, original) = louds.subtree(root);
+
+                // Naive breadth-first walk from `root` with a queue.
+                let mut queue = std::collections::VecDeque::new();
+                queue.push_back(root.value());
+                let (mut expected_degrees, mut expected_original) = (vec![], vec![]);
+                while let Some(node_num) = queue.pop_front() {
+                    let node_num = LoudsNodeNum::new(node_num);
+                    expected_degrees.push(louds.degree(node_num));
+                    for index in louds.children(node_num) {
+                        queue.push_back(louds.index_to_node_num(index).value());
+                    }
+                    expected_original.push(node_num);
+                }
+
+                assert_eq!(degrees(&subtree), expected_degrees);
+                assert_eq!(original, expected_original);
+            }
+        }
+    }
+
+    #[test]
+    fn bp_dfuds_fuzzing_test() {
+        let samples = 100;
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..samples {
+            let bs = generate_lbs(&mut rng);
+            eprintln!("build(): LBS = \"{}\"", bs.str());
+            let louds = LoudsBuilder::from_bit_string(bs).build();
+            let preorder: Vec<LoudsNodeNum> = louds.dfs_preorder().collect();
+            let n_nodes = preorder.len() as u64;
+
+            let (bp, node_nums) = louds.to_bp();
+            assert_eq!(bp.length(), 2 * n_nodes);
+            assert_eq!(node_nums, preorder);
+            let (builder, rebuilt_node_nums) = LoudsBuilder::from_bp(&bp);
+            assert_eq!(degrees(&builder.build()), degrees(&louds));
+            assert_eq!(rebuilt_node_nums, preorder);
+
+            let (dfuds, node_nums) = louds.to_dfuds();
+            assert_eq!(dfuds.length(), 2 * n_nodes);
+            assert_eq!(node_nums, preorder);
+            let (builder, rebuilt_node_nums) = LoudsBuilder::from_dfuds(&dfuds);
+            assert_eq!(degrees(&builder.build()), degrees(&louds));
+            assert_eq!(rebuilt_node_nums, preorder);
+        }
+    }
+
+    #[test]
+    fn leaf_rank_select_fuzzing_test() {
+        let samples = 100;
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..samples {
+            let bs = generate_lbs(&mut rng);
+            eprintln!("build(): LBS = \"{}\"", bs.str());
+            let louds = LoudsBuilder::from_bit_string(bs).build();
+
+            let mut leaves_cnt = 0;
+            for node_num in louds.bfs() {
+                assert_eq!(louds.leaf_rank(node_num), leaves_cnt);
+                if louds.is_leaf(node_num) {
+                    assert_eq!(louds.leaf_select(leaves_cnt), Some(node_num));
+                    leaves_cnt += 1;
+                }
+            }
+            assert_eq!(louds.leaf_select(leaves_cnt), None);
+        }
+    }
+
+    #[test]
+    fn subtree_size_descendants_fuzzing_test() {
+        let samples = 100;
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..samples {
+            let bs = generate_lbs(&mut rng);
+            eprintln!("build(): LBS = \"{}\"", bs.str());
+            let louds = LoudsBuilder::from_bit_string(bs).build();
+
+            // Subtree sizes by summing up children's in reverse level order.
+            let n_nodes = louds.bfs().count();
+            let mut sizes = vec![1u64; n_nodes + 1];
+            for node_num in (1..=n_nodes as u64).rev() {
+                let node_num = LoudsNodeNum::new(node_num);
+                for index in louds.children(node_num) {
+                    sizes[node_num.value() as usize] +=
+                        sizes[louds.index_to_node_num(index).value() as usize];
+                }
+            }
+
+            for node_num in louds.bfs() {
+                assert_eq!(
+                    louds.subtree_size(node_num),
+                    sizes[node_num.value() as usize]
+                );
+
+                let (_, original) = louds.subtree(node_num);
+                assert_eq!(
+                    louds.descendants(node_num).collect::<Vec<LoudsNodeNum>>(),
+                    original[1..].to_vec()
+                );
+            }
+        }
+    }
 }
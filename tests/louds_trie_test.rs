@@ -0,0 +1,57 @@
+mod louds_trie_feature_test {
+    use rand::prelude::*;
+    use std::collections::BTreeSet;
+    use succinct_rs::LoudsTrieBuilder;
+
+    fn generate_key(rng: &mut ThreadRng) -> Vec<u8> {
+        let len = rng.gen_range(0, 6);
+        (0..len).map(|_| b"abc"[rng.gen_range(0, 3)]).collect()
+    }
+
+    #[test]
+    fn fuzzing_test() {
+        let samples = 100;
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..samples {
+            let n_keys = rng.gen_range(0, 50);
+            let keys: Vec<Vec<u8>> = (0..n_keys).map(|_| generate_key(&mut rng)).collect();
+            eprintln!("keys = {:?}", keys);
+
+            let mut builder = LoudsTrieBuilder::new();
+            for key in &keys {
+                builder.add(key);
+            }
+            let trie = builder.build();
+            let set: BTreeSet<Vec<u8>> = keys.into_iter().collect();
+
+            assert_eq!(trie.len(), set.len() as u64);
+            let mut ids = BTreeSet::new();
+            for key in &set {
+                let id = trie.get(key).unwrap();
+                assert_eq!(trie.key(id), *key);
+                ids.insert(id);
+            }
+            assert_eq!(ids, (0..set.len() as u64).collect());
+
+            for _ in 0..20 {
+                let query = generate_key(&mut rng);
+                assert_eq!(trie.contains(&query), set.contains(&query));
+
+                let prefixes: Vec<Vec<u8>> = set
+                    .iter()
+                    .filter(|k| query.starts_with(k))
+                    .cloned()
+                    .collect();
+                assert_eq!(trie.prefix_search(&query), prefixes);
+
+                let completions: Vec<Vec<u8>> = set
+                    .iter()
+                    .filter(|k| k.starts_with(&query))
+                    .cloned()
+                    .collect();
+                assert_eq!(trie.predictive_search(&query), completions);
+            }
+        }
+    }
+}
@@ -0,0 +1,179 @@
+mod ordinal_tree_feature_test {
+    use rand::prelude::*;
+    use succinct_rs::ordinal_tree;
+    use succinct_rs::{Louds, LoudsBuilder, LoudsNodeNum, OrdinalChildren, OrdinalTree};
+
+    /// Reference implementation of `OrdinalTree` with plain pointers (ids into `nodes`).
+    struct PointerTree {
+        nodes: Vec<PointerNode>,
+    }
+
+    struct PointerNode {
+        parent: Option<usize>,
+        children: Vec<usize>,
+        /// Position in the parent's `children`.
+        position: usize,
+    }
+
+    impl PointerTree {
+        /// `parents[id]` is the parent id of node `id`; node 0 is the root. Children are ordered by their ids.
+        fn from_parents(parents: &[Option<usize>]) -> Self {
+            let mut nodes: Vec<PointerNode> = parents
+                .iter()
+                .map(|&parent| PointerNode {
+                    parent,
+                    children: vec![],
+                    position: 0,
+                })
+                .collect();
+            for (id, parent) in parents.iter().enumerate() {
+                if let Some(parent) = *parent {
+                    nodes[id].position = nodes[parent].children.len();
+                    nodes[parent].children.push(id);
+                }
+            }
+            PointerTree { nodes }
+        }
+    }
+
+    impl OrdinalTree for PointerTree {
+        type Node = usize;
+        type Children<'a> = OrdinalChildren<'a, Self>;
+
+        fn root(&self) -> usize {
+            0
+        }
+
+        fn parent_node(&self, node: usize) -> Option<usize> {
+            self.nodes[node].parent
+        }
+
+        fn first_child_node(&self, node: usize) -> Option<usize> {
+            self.nodes[node].children.first().copied()
+        }
+
+        fn next_sibling_node(&self, node: usize) -> Option<usize> {
+            let parent = self.nodes[node].parent?;
+            self.nodes[parent]
+                .children
+                .get(self.nodes[node].position + 1)
+                .copied()
+        }
+
+        fn child_nodes(&self, node: usize) -> OrdinalChildren<'_, Self> {
+            OrdinalChildren::new(self, self.first_child_node(node))
+        }
+
+        fn nodes_cnt(&self) -> u64 {
+            self.nodes.len() as u64
+        }
+    }
+
+    /// Degree sequence in BFS order, which determines the whole LBS (unlike `Display`, which shows only its head).
+    fn degrees(louds: &Louds) -> Vec<u64> {
+        louds.bfs().map(|node_num| louds.degree(node_num)).collect()
+    }
+
+    /// Random tree where each node's parent has a smaller id.
+    fn generate_parents(rng: &mut ThreadRng) -> Vec<Option<usize>> {
+        let n = rng.gen_range(1, 100);
+        (0..n)
+            .map(|id| {
+                if id == 0 {
+                    None
+                } else {
+                    Some(rng.gen_range(0, id))
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn louds_matches_pointer_tree_test() {
+        let samples = 100;
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..samples {
+            let parents = generate_parents(&mut rng);
+            eprintln!("parents = {:?}", parents);
+            let ptree = PointerTree::from_parents(&parents);
+
+            let (louds, ids) = ordinal_tree::to_louds(&ptree);
+            let (expected, node_nums) = LoudsBuilder::from_parent_array(&parents);
+            assert_eq!(degrees(&louds), degrees(&expected.build()));
+            for (k, &id) in ids.iter().enumerate() {
+                assert_eq!(node_nums[id], LoudsNodeNum::new(k as u64 + 1));
+            }
+            let to_id = |node_num: LoudsNodeNum| ids[node_num.value() as usize - 1];
+
+            // `Louds` overrides the derived methods, while `PointerTree` uses the defaults.
+            assert_eq!(louds.nodes_cnt(), ptree.nodes_cnt());
+            for node_num in louds.bfs() {
+                let id = to_id(node_num);
+                assert_eq!(
+                    louds.parent_node(node_num).map(to_id),
+                    ptree.parent_node(id)
+                );
+                assert_eq!(
+                    louds
+                        .child_nodes(node_num)
+                        .map(to_id)
+                        .collect::<Vec<usize>>(),
+                    ptree.child_nodes(id).collect::<Vec<usize>>()
+                );
+                assert_eq!(
+                    louds.first_child_node(node_num).map(to_id),
+                    ptree.first_child_node(id)
+                );
+                assert_eq!(
+                    louds.next_sibling_node(node_num).map(to_id),
+                    ptree.next_sibling_node(id)
+                );
+                assert_eq!(louds.children_cnt(node_num), ptree.children_cnt(id));
+                assert_eq!(louds.is_leaf_node(node_num), ptree.is_leaf_node(id));
+                assert_eq!(louds.node_depth(node_num), ptree.node_depth(id));
+            }
+
+            let map_ids =
+                |nodes: Vec<LoudsNodeNum>| nodes.into_iter().map(to_id).collect::<Vec<usize>>();
+            assert_eq!(map_ids(louds.level_order()), ptree.level_order());
+            assert_eq!(map_ids(louds.preorder()), ptree.preorder());
+            assert_eq!(map_ids(louds.postorder()), ptree.postorder());
+
+            let n = parents.len();
+            for _ in 0..20 {
+                let (a, b) = (rng.gen_range(0, n), rng.gen_range(0, n));
+                let lca = ptree.lowest_common_ancestor(a, b);
+                assert_eq!(
+                    louds.lowest_common_ancestor(node_nums[a], node_nums[b]),
+                    node_nums[lca]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn to_louds_of_louds_test() {
+        let louds: Louds =
+            LoudsBuilder::from_degrees(vec![3, 1, 0, 3, 0, 0, 1, 2, 0, 0, 0]).build();
+        let (copied, nodes) = ordinal_tree::to_louds(&louds);
+        assert_eq!(degrees(&copied), degrees(&louds));
+        assert_eq!(nodes, louds.bfs().collect::<Vec<LoudsNodeNum>>());
+    }
+
+    #[test]
+    fn serialize_test() {
+        let mut rng = rand::thread_rng();
+        let parents = generate_parents(&mut rng);
+        eprintln!("parents = {:?}", parents);
+        let ptree = PointerTree::from_parents(&parents);
+
+        let mut buf = vec![];
+        let ids = ordinal_tree::serialize(&ptree, &mut buf).unwrap();
+        let louds = Louds::deserialize(&mut &buf[..]).unwrap();
+
+        let (expected, expected_ids) = ordinal_tree::to_louds(&ptree);
+        assert_eq!(degrees(&louds), degrees(&expected));
+        assert_eq!(ids, expected_ids);
+    }
+}
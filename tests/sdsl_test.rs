@@ -0,0 +1,177 @@
+use std::fs::File;
+use std::io::{BufReader, Cursor};
+use succinct_rs::{BitString, SuccinctBitVector, SuccinctBitVectorBuilder};
+
+const FIXTURES_DIR: &str = "tests/fixtures/sdsl";
+
+fn small_bits(i: u64) -> bool {
+    let s = "0000100001000001000001001100000000100000000001011010000000010000001";
+    s.as_bytes()[i as usize] == b'1'
+}
+
+fn dense_bits(i: u64) -> bool {
+    (i * i + 3 * i) % 7 < 3
+}
+
+fn sparse_bits(i: u64) -> bool {
+    i.is_multiple_of(61)
+}
+
+/// (name, length, i-th bit)
+type Fixture = (&'static str, u64, fn(u64) -> bool);
+
+fn fixtures() -> Vec<Fixture> {
+    vec![
+        ("small", 67, small_bits),
+        ("dense", 10000, dense_bits),
+        ("sparse", 1 << 20, sparse_bits),
+    ]
+}
+
+fn open(name: &str, ext: &str) -> BufReader<File> {
+    let path = format!("{}/{}.{}", FIXTURES_DIR, name, ext);
+    BufReader::new(File::open(&path).unwrap_or_else(|e| panic!("{}: {}", path, e)))
+}
+
+fn read(name: &str, ext: &str) -> Vec<u8> {
+    let path = format!("{}/{}.{}", FIXTURES_DIR, name, ext);
+    std::fs::read(&path).unwrap_or_else(|e| panic!("{}: {}", path, e))
+}
+
+#[test]
+fn import_bit_vector() {
+    for (name, length, bit) in fixtures() {
+        let bv = SuccinctBitVector::from_sdsl_bit_vector(&mut open(name, "bit_vector")).unwrap();
+        assert_eq!(bv.length(), length, "{}", name);
+
+        let mut rank = 0;
+        for i in 0..length {
+            assert_eq!(bv.access(i), bit(i), "{}: access({})", name, i);
+            if bit(i) {
+                rank += 1;
+                assert_eq!(bv.select(rank), Some(i), "{}: select({})", name, rank);
+            }
+            if i % 97 == 0 {
+                assert_eq!(bv.rank(i), rank, "{}: rank({})", name, i);
+            }
+        }
+    }
+}
+
+#[test]
+fn check_supports() {
+    for (name, _, _) in fixtures() {
+        let bv = SuccinctBitVector::from_sdsl_bit_vector(&mut open(name, "bit_vector")).unwrap();
+        bv.check_sdsl_rank_support_v(&mut open(name, "rank_support_v"))
+            .unwrap();
+        bv.check_sdsl_select_support_mcl(&mut open(name, "select_support_mcl"))
+            .unwrap();
+    }
+}
+
+#[test]
+fn check_supports_of_another_bit_vector() {
+    let bv = SuccinctBitVector::from_sdsl_bit_vector(&mut open("dense", "bit_vector")).unwrap();
+    assert!(bv
+        .check_sdsl_rank_support_v(&mut open("sparse", "rank_support_v"))
+        .is_err());
+    assert!(bv
+        .check_sdsl_select_support_mcl(&mut open("sparse", "select_support_mcl"))
+        .is_err());
+}
+
+#[test]
+fn export_matches_fixtures() {
+    for (name, length, bit) in fixtures() {
+        let mut builder = SuccinctBitVectorBuilder::from_length(length);
+        for i in (0..length).filter(|i| bit(*i)) {
+            builder.set_bit(i);
+        }
+        let bv = builder.build();
+
+        for (ext, written) in [
+            ("bit_vector", {
+                let mut buf = vec![];
+                bv.write_sdsl_bit_vector(&mut buf).unwrap();
+                buf
+            }),
+            ("rank_support_v", {
+                let mut buf = vec![];
+                bv.write_sdsl_rank_support_v(&mut buf).unwrap();
+                buf
+            }),
+            ("select_support_mcl", {
+                let mut buf = vec![];
+                bv.write_sdsl_select_support_mcl(&mut buf).unwrap();
+                buf
+            }),
+        ] {
+            assert!(written == read(name, ext), "{}.{}", name, ext);
+        }
+    }
+}
+
+#[test]
+fn round_trip() {
+    for s in &[
+        "0",
+        "1",
+        "01001",
+        "1000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0000_0001",
+    ] {
+        let bv = SuccinctBitVectorBuilder::from_bit_string(BitString::new(s)).build();
+
+        let (mut bv_buf, mut rank_buf, mut select_buf) = (vec![], vec![], vec![]);
+        bv.write_sdsl_bit_vector(&mut bv_buf).unwrap();
+        bv.write_sdsl_rank_support_v(&mut rank_buf).unwrap();
+        bv.write_sdsl_select_support_mcl(&mut select_buf).unwrap();
+
+        let loaded = SuccinctBitVector::from_sdsl_bit_vector(&mut Cursor::new(bv_buf)).unwrap();
+        assert_eq!(loaded.storage(), bv.storage());
+        loaded
+            .check_sdsl_rank_support_v(&mut Cursor::new(rank_buf))
+            .unwrap();
+        loaded
+            .check_sdsl_select_support_mcl(&mut Cursor::new(select_buf))
+            .unwrap();
+    }
+}
+
+#[test]
+fn import_empty_bit_vector() {
+    let mut buf = Cursor::new(vec![0u8; 8]);
+    assert!(SuccinctBitVector::from_sdsl_bit_vector(&mut buf).is_err());
+}
+
+#[test]
+fn import_truncated_bit_vector() {
+    let mut buf = read("small", "bit_vector");
+    buf.pop();
+    assert!(SuccinctBitVector::from_sdsl_bit_vector(&mut Cursor::new(buf)).is_err());
+}
+
+#[test]
+fn import_bit_vector_with_huge_length() {
+    let mut buf = (u64::MAX / 2).to_le_bytes().to_vec();
+    buf.extend_from_slice(&[0u8; 8]);
+    let err = SuccinctBitVector::from_sdsl_bit_vector(&mut Cursor::new(buf)).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn select_support_mcl_logn() {
+    // sdsl-lite's `initData()`: logn = hi(length rounded up to a multiple of 64) + 1.
+    for &(name, expected_logn) in &[("small", 8u32), ("dense", 14), ("sparse", 21)] {
+        let buf = read(name, "select_support_mcl");
+        let logn = u32::from_le_bytes([buf[8], buf[9], buf[10], buf[11]]);
+        assert_eq!(logn, expected_logn, "{}", name);
+    }
+
+    let bv = SuccinctBitVector::from_sdsl_bit_vector(&mut open("small", "bit_vector")).unwrap();
+    let mut buf = read("small", "select_support_mcl");
+    buf[8] -= 1;
+    let err = bv
+        .check_sdsl_select_support_mcl(&mut Cursor::new(buf))
+        .unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+}
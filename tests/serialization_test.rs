@@ -0,0 +1,175 @@
+use rand::prelude::*;
+use succinct_rs::{
+    BitString, Louds, LoudsBuilder, LoudsNodeNum, SuccinctBitVector, SuccinctBitVectorBuilder,
+};
+
+fn serialize(bv: &SuccinctBitVector) -> Vec<u8> {
+    let mut buf = vec![];
+    bv.serialize(&mut buf).unwrap();
+    buf
+}
+
+fn serialize_compact(bv: &SuccinctBitVector) -> Vec<u8> {
+    let mut buf = vec![];
+    bv.serialize_compact(&mut buf).unwrap();
+    buf
+}
+
+#[test]
+fn succinct_bit_vector_round_trip() {
+    let mut rng = rand::thread_rng();
+
+    for length in (1..300).chain(vec![1 << 12, (1 << 12) + 1, 12345]) {
+        let mut builder = SuccinctBitVectorBuilder::from_length(length);
+        for i in 0..length {
+            if rng.gen::<bool>() {
+                builder.set_bit(i);
+            }
+        }
+        let bv = builder.build();
+
+        let full = serialize(&bv);
+        let compact = serialize_compact(&bv);
+        assert!(compact.len() < full.len());
+
+        for buf in &[full, compact] {
+            let loaded = SuccinctBitVector::deserialize(&mut &buf[..]).unwrap();
+            assert_eq!(loaded.length(), length);
+            for i in 0..length {
+                assert_eq!(loaded.access(i), bv.access(i));
+                assert_eq!(loaded.rank(i), bv.rank(i));
+            }
+            let ones = bv.rank(length - 1);
+            for num in 0..=(ones + 1).min(length) {
+                assert_eq!(loaded.select(num), bv.select(num));
+            }
+        }
+    }
+}
+
+#[test]
+fn louds_round_trip() {
+    let bs = BitString::new("10_1110_10_0_1110_0_0_10_110_0_0_0");
+    let louds = LoudsBuilder::from_bit_string(bs).build();
+
+    let mut full = vec![];
+    louds.serialize(&mut full).unwrap();
+    let mut compact = vec![];
+    louds.serialize_compact(&mut compact).unwrap();
+    assert!(compact.len() < full.len());
+
+    for buf in &[full, compact] {
+        let loaded = Louds::deserialize(&mut &buf[..]).unwrap();
+        assert_eq!(format!("{}", loaded), format!("{}", louds));
+        for node_num in 1..=11 {
+            let node_num = LoudsNodeNum::new(node_num);
+            assert_eq!(
+                loaded.parent_to_children(node_num),
+                louds.parent_to_children(node_num)
+            );
+        }
+    }
+}
+
+#[test]
+fn deserialize_another_kind() {
+    let bv = SuccinctBitVectorBuilder::from_bit_string(BitString::new("10_0")).build();
+    assert!(Louds::deserialize(&mut &serialize(&bv)[..]).is_err());
+
+    let louds = LoudsBuilder::from_bit_string(BitString::new("10_0")).build();
+    let mut buf = vec![];
+    louds.serialize(&mut buf).unwrap();
+    assert!(SuccinctBitVector::deserialize(&mut &buf[..]).is_err());
+}
+
+#[test]
+fn deserialize_broken_header() {
+    let bv = SuccinctBitVectorBuilder::from_bit_string(BitString::new("01001")).build();
+
+    // magic
+    let mut buf = serialize(&bv);
+    buf[0] = b'X';
+    assert!(SuccinctBitVector::deserialize(&mut &buf[..]).is_err());
+
+    // version
+    let mut buf = serialize(&bv);
+    buf[4] = 0;
+    assert!(SuccinctBitVector::deserialize(&mut &buf[..]).is_err());
+
+    // unknown flags
+    let mut buf = serialize(&bv);
+    buf[6] |= 0b1000_0000;
+    assert!(SuccinctBitVector::deserialize(&mut &buf[..]).is_err());
+}
+
+#[test]
+fn deserialize_truncated() {
+    let bv = SuccinctBitVectorBuilder::from_bit_string(BitString::new("01001")).build();
+    for buf in &[serialize(&bv), serialize_compact(&bv)] {
+        for len in 0..buf.len() {
+            assert!(SuccinctBitVector::deserialize(&mut &buf[..len]).is_err());
+        }
+    }
+}
+
+#[test]
+fn deserialize_huge_length() {
+    let bv = SuccinctBitVectorBuilder::from_bit_string(BitString::new("01001")).build();
+    for buf in &mut [serialize(&bv), serialize_compact(&bv)] {
+        // Length follows the 8-byte header.
+        buf[8..16].copy_from_slice(&(u64::MAX / 2).to_le_bytes());
+        let err = SuccinctBitVector::deserialize(&mut &buf[..]).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+}
+
+#[test]
+fn deserialize_broken_directory() {
+    let bv = SuccinctBitVectorBuilder::from_bit_string(BitString::new("01001")).build();
+    let mut buf = serialize(&bv);
+
+    // Chunks count follows the 8-byte header, 8-byte length and 1 word.
+    buf[24] += 1;
+    assert!(SuccinctBitVector::deserialize(&mut &buf[..]).is_err());
+}
+
+#[test]
+fn deserialize_corrupted_directory_values() {
+    // After the 8-byte header, 8-byte length, 1 word and 8-byte chunks count,
+    // the first chunk's 8-byte value, 2-byte length and 2-byte blocks count are followed by its first block's 2-byte value.
+    for &offset in &[32, 44] {
+        let bv = SuccinctBitVectorBuilder::from_bit_string(BitString::new("01001")).build();
+        let mut buf = serialize(&bv);
+        buf[offset] += 1;
+        let err = SuccinctBitVector::deserialize(&mut &buf[..]).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            std::io::ErrorKind::InvalidData,
+            "offset {}",
+            offset
+        );
+
+        let louds =
+            LoudsBuilder::from_bit_string(BitString::new("10_1110_10_0_1110_0_0_10_110_0_0_0"))
+                .build();
+        let mut buf = vec![];
+        louds.serialize(&mut buf).unwrap();
+        buf[offset] += 1;
+        let err = Louds::deserialize(&mut &buf[..]).unwrap_err();
+        assert_eq!(
+            err.kind(),
+            std::io::ErrorKind::InvalidData,
+            "offset {}",
+            offset
+        );
+    }
+}
+
+#[test]
+fn deserialize_invalid_lbs() {
+    // Serialize "10_1" (not a LOUDS) as a compact Louds by reusing SuccinctBitVector's body.
+    let bv = SuccinctBitVectorBuilder::from_bit_string(BitString::new("10_1")).build();
+    let mut buf = serialize_compact(&bv);
+    buf[5] = 1; // kind: Louds
+    assert!(Louds::deserialize(&mut &buf[..]).is_err());
+}
@@ -1,4 +1,4 @@
-use succinct_rs::{BitString, SuccinctBitVectorBuilder};
+use succinct_rs::{BitString, SuccinctBitVector, SuccinctBitVectorBuilder};
 
 #[test]
 fn build_from_length() {
@@ -36,6 +36,42 @@ fn build_from_bit_string_and_set_bit() {
     assert_eq!(bv.access(1), true);
 }
 
+#[test]
+fn build_from_storage() {
+    use std::sync::Arc;
+
+    // `01001`
+    let words = vec![0b10010u64];
+
+    let bv = SuccinctBitVector::from_storage(words.clone(), 5);
+    assert_eq!(bv.rank(4), 2);
+    let bv = SuccinctBitVector::from_storage(words.clone().into_boxed_slice(), 5);
+    assert_eq!(bv.rank(4), 2);
+    let bv = SuccinctBitVector::from_storage(Arc::<[u64]>::from(words.clone()), 5);
+    assert_eq!(bv.rank(4), 2);
+    let bv = SuccinctBitVector::from_storage(&words[..], 5);
+    assert_eq!(bv.rank(4), 2);
+}
+
+#[test]
+fn map_storage() {
+    use std::sync::Arc;
+
+    let bv = SuccinctBitVectorBuilder::from_bit_string(BitString::new("01001")).build();
+    assert_eq!(bv.length(), 5);
+    assert_eq!(bv.storage(), &vec![0b10010u64]);
+
+    let bv: SuccinctBitVector<Arc<[u64]>> = bv.map_storage(Arc::from);
+    let cloned = bv.clone();
+    assert!(Arc::ptr_eq(bv.storage(), cloned.storage()));
+    for i in 0..5 {
+        assert_eq!(cloned.access(i), bv.access(i));
+        assert_eq!(cloned.rank(i), bv.rank(i));
+    }
+    assert_eq!(cloned.select(2), Some(4));
+    assert_eq!(cloned.into_storage().to_vec(), vec![0b10010u64]);
+}
+
 #[test]
 fn fuzzing_test() {
     let samples = 10000;